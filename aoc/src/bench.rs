@@ -0,0 +1,101 @@
+use std::time::Duration;
+
+use common::json::Json;
+
+/// Time spent on one run of a part, `parse` and `solve` are measured
+/// separately so a slow parser doesn't hide behind the solver.
+#[derive(Debug, Clone, Copy)]
+pub struct Timing {
+    pub parse: Duration,
+    pub solve: Duration,
+}
+
+impl Timing {
+    pub fn total(&self) -> Duration {
+        self.parse + self.solve
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+}
+
+/// Nearest-rank percentile of already sorted samples.
+fn percentile(sorted: &[Duration], percent: usize) -> Duration {
+    let rank = (sorted.len() * percent).div_ceil(100).max(1);
+    sorted[rank - 1]
+}
+
+impl Stats {
+    pub fn new(samples: impl Iterator<Item = Duration>) -> Option<Self> {
+        let mut samples = samples.collect::<Vec<Duration>>();
+        samples.sort();
+
+        Some(Stats {
+            min: *samples.first()?,
+            median: percentile(&samples, 50),
+            p95: percentile(&samples, 95),
+        })
+    }
+
+    pub fn to_json(self) -> Json {
+        Json::object([
+            ("min_ns", self.min.as_nanos().into()),
+            ("median_ns", self.median.as_nanos().into()),
+            ("p95_ns", self.p95.as_nanos().into()),
+        ])
+    }
+}
+
+pub struct Report {
+    pub parse: Stats,
+    pub solve: Stats,
+    pub total: Stats,
+}
+
+impl Report {
+    pub fn new(timings: &[Timing]) -> Option<Self> {
+        Some(Report {
+            parse: Stats::new(timings.iter().map(|timing| timing.parse))?,
+            solve: Stats::new(timings.iter().map(|timing| timing.solve))?,
+            total: Stats::new(timings.iter().map(Timing::total))?,
+        })
+    }
+
+    pub fn print_table(&self) {
+        println!("{:<8}{:>14}{:>14}{:>14}", "", "min", "median", "p95");
+        for (name, stats) in [
+            ("parse", self.parse),
+            ("solve", self.solve),
+            ("total", self.total),
+        ] {
+            println!(
+                "{:<8}{:>14}{:>14}{:>14}",
+                name,
+                format!("{:?}", stats.min),
+                format!("{:?}", stats.median),
+                format!("{:?}", stats.p95)
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats_use_nearest_rank() {
+        let samples = (1..=20).rev().map(Duration::from_millis);
+
+        let stats = Stats::new(samples).unwrap();
+
+        assert_eq!(Duration::from_millis(1), stats.min);
+        assert_eq!(Duration::from_millis(10), stats.median);
+        assert_eq!(Duration::from_millis(19), stats.p95);
+        assert_eq!(None, Stats::new(std::iter::empty()));
+    }
+}
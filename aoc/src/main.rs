@@ -0,0 +1,312 @@
+use std::{env, error::Error, fs, path::Path, process::ExitCode, time::Instant};
+
+use bench::{Report, Timing};
+use common::{
+    cli::{Args, Cli, CliError},
+    exit_code, fnv1a, input_label,
+    json::Json,
+    read_input, Answer, Detail, Part, Solution,
+};
+use day1::Day1;
+use day2::Day2;
+use day3::Day3;
+use day4::Day4;
+use day5::Day5;
+use day6::Day6;
+use day7::Day7;
+use day8::Day8;
+use day9::Day9;
+use verify::{check, expected_answer, DEFAULT_ROOT};
+
+mod bench;
+mod verify;
+
+const DEFAULT_ITERATIONS: usize = 10;
+
+/// Reads `--day` and `--part`, both required by `run` and `bench`, and
+/// finds the solver of the day.
+fn day_and_part(args: &Args) -> Result<(u8, Part, Solver), CliError> {
+    let day = args.required::<u8>("--day")?;
+    let solver = solver(day).ok_or_else(|| args.error(format!("unknown day {}", day)))?;
+
+    Ok((day, args.required::<Part>("--part")?, solver))
+}
+
+struct Execution {
+    answer: Answer,
+    timing: Timing,
+    details: Option<Vec<Detail>>,
+}
+
+type Solver = fn(&str, Part, bool) -> Result<Execution, Box<dyn Error>>;
+
+/// Solves a part, and when asked for `details` breaks the answer down per
+/// item too. That is left out of the timings.
+fn solve<S: Solution>(input: &str, part: Part, details: bool) -> Result<Execution, Box<dyn Error>> {
+    let start = Instant::now();
+    let input = S::parse(input)?;
+    let parse = start.elapsed();
+
+    let start = Instant::now();
+    let answer = S::solve(&input, part)?;
+    let solve = start.elapsed();
+
+    let details = if details {
+        Some(S::details(&input, part)?)
+    } else {
+        None
+    };
+
+    Ok(Execution {
+        answer,
+        timing: Timing { parse, solve },
+        details,
+    })
+}
+
+/// Every day's solver next to its `Solution::DAY`, which is what `--day`
+/// is matched against, and whether it breaks its answers down.
+const DAYS: [(u8, Solver, bool); 9] = [
+    (Day1::DAY, solve::<Day1>, Day1::HAS_DETAILS),
+    (Day2::DAY, solve::<Day2>, Day2::HAS_DETAILS),
+    (Day3::DAY, solve::<Day3>, Day3::HAS_DETAILS),
+    (Day4::DAY, solve::<Day4>, Day4::HAS_DETAILS),
+    (Day5::DAY, solve::<Day5>, Day5::HAS_DETAILS),
+    (Day6::DAY, solve::<Day6>, Day6::HAS_DETAILS),
+    (Day7::DAY, solve::<Day7>, Day7::HAS_DETAILS),
+    (Day8::DAY, solve::<Day8>, Day8::HAS_DETAILS),
+    (Day9::DAY, solve::<Day9>, Day9::HAS_DETAILS),
+];
+
+fn solver(day: u8) -> Option<Solver> {
+    DAYS.iter()
+        .find(|&&(number, _, _)| number == day)
+        .map(|&(_, solver, _)| solver)
+}
+
+fn has_details(day: u8) -> bool {
+    DAYS.iter()
+        .any(|&(number, _, details)| number == day && details)
+}
+
+fn execution_json(day: u8, part: Part, path: &str, input: &str, execution: Execution) -> Json {
+    let mut fields = vec![
+        ("day", day.into()),
+        ("part", part.number().into()),
+        ("answer", execution.answer.into()),
+        ("input", path.into()),
+        (
+            "input_hash",
+            format!("fnv1a:{:016x}", fnv1a(input.as_bytes())).into(),
+        ),
+        (
+            "timings",
+            Json::object([
+                ("parse_ns", execution.timing.parse.as_nanos().into()),
+                ("solve_ns", execution.timing.solve.as_nanos().into()),
+            ]),
+        ),
+    ];
+    if let Some(details) = execution.details {
+        let details = details
+            .into_iter()
+            .map(|detail| {
+                Json::object([("line", detail.line.into()), ("value", detail.value.into())])
+            })
+            .collect::<Vec<Json>>();
+        fields.push(("details", details.into()));
+    }
+
+    Json::object(fields)
+}
+
+fn run(args: &[String]) -> Result<(), Box<dyn Error>> {
+    let args = Cli::new("aoc run", "Solves a part of a day")
+        .option("--day", "<1-9>", "day to solve")
+        .option("--part", "<1|2>", "part to solve")
+        .option("--format", "<text|json>", "output format, text by default")
+        .flag("--details", "also print what every line adds to the answer")
+        .inputs()
+        .parse(args)?;
+    let (day, part, solver) = day_and_part(&args)?;
+    let json = match args.value("--format") {
+        None | Some("text") => false,
+        Some("json") => true,
+        Some(format) => return Err(args.error(format!("unknown format {}", format)).into()),
+    };
+
+    let details = args.flag("--details");
+    if details && !has_details(day) {
+        let message = format!(
+            "day {} takes no --details, it can't tell what every line adds",
+            day
+        );
+        return Err(args.error(message).into());
+    }
+    for path in &args.inputs {
+        let input = read_input(path, args.flag("--strict"))?;
+        let execution =
+            solver(&input, part, details).map_err(|error| format!("{}: {}", path, error))?;
+
+        if json {
+            println!("{}", execution_json(day, part, path, &input, execution));
+        } else {
+            for detail in execution.details.iter().flatten() {
+                println!(
+                    "{}line {}: {}",
+                    input_label(path, args.inputs.len()),
+                    detail.line,
+                    detail.value
+                );
+            }
+
+            println!(
+                "{}total {}",
+                input_label(path, args.inputs.len()),
+                execution.answer
+            );
+        }
+    }
+
+    Ok(())
+}
+
+fn bench(args: &[String]) -> Result<(), Box<dyn Error>> {
+    let args = Cli::new("aoc bench", "Times the parsing and solving of a part")
+        .option("--day", "<1-9>", "day to time")
+        .option("--part", "<1|2>", "part to time")
+        .option("--iterations", "<n>", "times to run it, 10 by default")
+        .option("--report", "<file>", "also write the timings as JSON")
+        .inputs()
+        .parse(args)?;
+    let (day, part, solver) = day_and_part(&args)?;
+    let iterations = args
+        .parse::<usize>("--iterations")?
+        .unwrap_or(DEFAULT_ITERATIONS);
+    if iterations == 0 {
+        return Err(args.error("invalid value for --iterations: 0").into());
+    }
+
+    let [path] = args.inputs.as_slice() else {
+        return Err(args.error("bench takes a single input file").into());
+    };
+
+    let input = read_input(path, args.flag("--strict"))?;
+    let mut answer = Answer::default();
+    let mut timings = Vec::with_capacity(iterations);
+    for _ in 0..iterations {
+        let execution =
+            solver(&input, part, false).map_err(|error| format!("{}: {}", path, error))?;
+        answer = execution.answer;
+        timings.push(execution.timing);
+    }
+
+    let report = Report::new(&timings).ok_or("no iterations were run")?;
+    println!(
+        "day {} part {}: total {}, {} iterations",
+        day,
+        part.number(),
+        answer,
+        iterations
+    );
+    report.print_table();
+
+    if let Some(report_path) = args.value("--report") {
+        let json = Json::object([
+            ("day", day.into()),
+            ("part", part.number().into()),
+            ("input", path.as_str().into()),
+            ("answer", answer.into()),
+            ("iterations", iterations.into()),
+            ("parse", report.parse.to_json()),
+            ("solve", report.solve.to_json()),
+            ("total", report.total.to_json()),
+        ]);
+        fs::write(report_path, format!("{}\n", json))?;
+    }
+
+    Ok(())
+}
+
+/// Runs every part of every day (or just `--day`) on its `input.txt` and
+/// compares the answers with the recorded ones.
+fn verify(args: &[String]) -> Result<(), Box<dyn Error>> {
+    let args = Cli::new(
+        "aoc verify",
+        "Checks every day's answers for its input.txt against the recorded ones",
+    )
+    .option("--day", "<1-9>", "only check this day")
+    .option(
+        "--root",
+        "<dir>",
+        "directory holding the day folders, the workspace aoc was built in by default",
+    )
+    .parse(args)?;
+    let root = Path::new(args.value("--root").unwrap_or(DEFAULT_ROOT));
+    let days = match args.parse::<u8>("--day")? {
+        None => DAYS
+            .iter()
+            .map(|&(day, solver, _)| (day, solver))
+            .collect::<Vec<(u8, Solver)>>(),
+        Some(day) => match solver(day) {
+            Some(solver) => vec![(day, solver)],
+            None => return Err(args.error(format!("unknown day {}", day)).into()),
+        },
+    };
+
+    println!(
+        "{:<5}{:<6}{:>20}{:>20}  status",
+        "day", "part", "expected", "answer"
+    );
+    let mut failures = 0;
+    for (day, solver) in days {
+        let input_path = root.join(format!("day{}", day)).join("input.txt");
+        let input = read_input(&input_path.to_string_lossy(), false);
+        for part in [Part::One, Part::Two] {
+            let expected = expected_answer(root, day, part.number())?;
+            let answer = match &input {
+                Ok(input) => solver(input, part, false)
+                    .map(|execution| execution.answer.to_string())
+                    .map_err(|error| error.to_string()),
+                Err(error) => Err(error.to_string()),
+            };
+
+            let status = check(expected.as_deref(), answer.as_deref().map_err(Clone::clone));
+            if status.is_failure() {
+                failures += 1;
+            }
+            println!(
+                "{:<5}{:<6}{:>20}{:>20}  {}",
+                day,
+                part.number(),
+                expected.as_deref().unwrap_or("-"),
+                answer.as_deref().unwrap_or("-"),
+                status
+            );
+        }
+    }
+
+    if failures > 0 {
+        return Err(format!("{} parts failed verification", failures).into());
+    }
+
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().collect();
+    let cli = Cli::new("aoc", "Runs the solvers of every day")
+        .command("run", "solve a part, see `aoc run --help`")
+        .command("bench", "time a part, see `aoc bench --help`")
+        .command("verify", "check the answers of every day");
+    let result = match args.get(1).map(String::as_str) {
+        Some("run") => run(&args[2..]),
+        Some("bench") => bench(&args[2..]),
+        Some("verify") => verify(&args[2..]),
+        Some("-h" | "--help") => Err(CliError::Help(cli.usage()).into()),
+        Some(command) => Err(cli.error(format!("unknown command {}", command)).into()),
+        None => Err(cli.error("missing command").into()),
+    };
+
+    exit_code(result)
+}
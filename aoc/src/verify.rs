@@ -0,0 +1,79 @@
+use std::{fmt, fs, io, path::Path};
+
+/// The workspace the binary was built from, where `verify` looks for the
+/// days unless told otherwise.
+pub const DEFAULT_ROOT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/..");
+
+/// The known answer of a day's part for its `input.txt`, recorded next to it
+/// in `<root>/day<N>/part<P>` exactly as the solver prints it
+/// (`total <answer>`).
+pub fn expected_answer(root: &Path, day: u8, part: u8) -> io::Result<Option<String>> {
+    let path = root
+        .join(format!("day{}", day))
+        .join(format!("part{}", part));
+    match fs::read_to_string(path) {
+        Ok(content) => Ok(parse_expected(&content)),
+        Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(error) => Err(error),
+    }
+}
+
+fn parse_expected(content: &str) -> Option<String> {
+    let content = content.trim();
+    let answer = content.strip_prefix("total ").unwrap_or(content).trim();
+    (!answer.is_empty()).then(|| answer.to_string())
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Mismatch,
+    Fail(String),
+    Missing,
+}
+
+impl Status {
+    pub fn is_failure(&self) -> bool {
+        matches!(self, Status::Mismatch | Status::Fail(_))
+    }
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Status::Pass => write!(f, "pass"),
+            Status::Mismatch => write!(f, "mismatch"),
+            Status::Fail(error) => write!(f, "fail: {}", error),
+            Status::Missing => write!(f, "missing"),
+        }
+    }
+}
+
+pub fn check(expected: Option<&str>, answer: Result<&str, String>) -> Status {
+    match (expected, answer) {
+        (_, Err(error)) => Status::Fail(error),
+        (None, Ok(_)) => Status::Missing,
+        (Some(expected), Ok(answer)) if expected == answer => Status::Pass,
+        (Some(_), Ok(_)) => Status::Mismatch,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_expected_accepts_the_solver_output() {
+        assert_eq!(Some("525181".to_string()), parse_expected("total 525181\n"));
+        assert_eq!(Some("-2".to_string()), parse_expected("-2"));
+        assert_eq!(None, parse_expected("\n"));
+    }
+
+    #[test]
+    fn test_check() {
+        assert_eq!(Status::Pass, check(Some("42"), Ok("42")));
+        assert_eq!(Status::Mismatch, check(Some("42"), Ok("41")));
+        assert_eq!(Status::Missing, check(None, Ok("42")));
+        assert!(check(Some("42"), Err("boom".to_string())).is_failure());
+    }
+}
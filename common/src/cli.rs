@@ -0,0 +1,228 @@
+use std::{collections::HashMap, error::Error, fmt, str::FromStr};
+
+/// Command line of a binary: the commands it knows, the options it takes
+/// and whether it reads input files. Builds its own usage text from that.
+pub struct Cli {
+    program: &'static str,
+    about: &'static str,
+    commands: Vec<(&'static str, &'static str)>,
+    options: Vec<(&'static str, &'static str, &'static str)>,
+    flags: Vec<(&'static str, &'static str)>,
+    inputs: bool,
+}
+
+/// What went wrong on the command line. `--help` is one of them so it stops
+/// the binary like any other, only it's not a failure.
+#[derive(Debug, PartialEq, Eq)]
+pub enum CliError {
+    Help(String),
+    Usage { message: String, usage: String },
+}
+
+impl fmt::Display for CliError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CliError::Help(usage) => write!(f, "{}", usage),
+            CliError::Usage { message, usage } => write!(f, "{}\n\n{}", message, usage),
+        }
+    }
+}
+
+impl Error for CliError {}
+
+/// The parsed command line.
+#[derive(Debug)]
+pub struct Args {
+    pub command: Option<String>,
+    pub inputs: Vec<String>,
+    values: HashMap<String, String>,
+    flags: Vec<String>,
+    usage: String,
+}
+
+impl Args {
+    pub fn value(&self, option: &str) -> Option<&str> {
+        self.values.get(option).map(String::as_str)
+    }
+
+    pub fn flag(&self, flag: &str) -> bool {
+        self.flags.iter().any(|set| set == flag)
+    }
+
+    /// The value of `option` parsed as a `T`, failing with the usage text
+    /// when it doesn't parse.
+    pub fn parse<T: FromStr>(&self, option: &str) -> Result<Option<T>, CliError> {
+        self.value(option)
+            .map(|value| {
+                value
+                    .parse::<T>()
+                    .map_err(|_| self.error(format!("invalid value for {}: {}", option, value)))
+            })
+            .transpose()
+    }
+
+    pub fn required<T: FromStr>(&self, option: &str) -> Result<T, CliError> {
+        self.parse(option)?
+            .ok_or_else(|| self.error(format!("missing {}", option)))
+    }
+
+    pub fn error(&self, message: impl Into<String>) -> CliError {
+        CliError::Usage {
+            message: message.into(),
+            usage: self.usage.clone(),
+        }
+    }
+}
+
+impl Cli {
+    pub fn new(program: &'static str, about: &'static str) -> Self {
+        Cli {
+            program,
+            about,
+            commands: Vec::new(),
+            options: Vec::new(),
+            flags: Vec::new(),
+            inputs: false,
+        }
+    }
+
+    pub fn command(mut self, name: &'static str, help: &'static str) -> Self {
+        self.commands.push((name, help));
+        self
+    }
+
+    /// An option followed by a value, e.g. `option("--day", "<1-9>", ...)`.
+    pub fn option(mut self, name: &'static str, value: &'static str, help: &'static str) -> Self {
+        self.options.push((name, value, help));
+        self
+    }
+
+    pub fn flag(mut self, name: &'static str, help: &'static str) -> Self {
+        self.flags.push((name, help));
+        self
+    }
+
+    /// Takes one or more input files, `-` being stdin. They are normalized
+    /// on load unless `--strict` is given, then they have to be clean.
+    pub fn inputs(mut self) -> Self {
+        self.inputs = true;
+        self.flag(
+            "--strict",
+            "fail on CRLF, a BOM or trailing whitespace instead of fixing them",
+        )
+    }
+
+    /// The one line summary shown along with errors.
+    pub fn synopsis(&self) -> String {
+        let mut synopsis = format!("usage: {}", self.program);
+        if !self.commands.is_empty() {
+            synopsis.push_str(" <command>");
+        }
+        synopsis.push_str(" [options]");
+        if self.inputs {
+            synopsis.push_str(" <input>...");
+        }
+
+        synopsis
+    }
+
+    /// The whole `--help` text.
+    pub fn usage(&self) -> String {
+        let mut usage = format!("{}\n\n{}", self.about, self.synopsis());
+
+        if !self.commands.is_empty() {
+            usage.push_str("\n\ncommands:");
+            for (name, help) in &self.commands {
+                usage.push_str(&format!("\n  {:<24}{}", name, help));
+            }
+        }
+
+        usage.push_str("\n\noptions:");
+        for (name, value, help) in &self.options {
+            let option = format!("{} {}", name, value);
+            usage.push_str(&format!("\n  {:<24}{}", option, help));
+        }
+        for (name, help) in &self.flags {
+            usage.push_str(&format!("\n  {:<24}{}", name, help));
+        }
+        usage.push_str(&format!("\n  {:<24}{}", "-h, --help", "print this help"));
+
+        if self.inputs {
+            usage.push_str("\n\n<input> is a puzzle input file, `-` reads it from stdin");
+        }
+
+        usage
+    }
+
+    fn short_usage(&self) -> String {
+        format!(
+            "{}\nrun `{} --help` for more information",
+            self.synopsis(),
+            self.program
+        )
+    }
+
+    pub fn error(&self, message: impl Into<String>) -> CliError {
+        CliError::Usage {
+            message: message.into(),
+            usage: self.short_usage(),
+        }
+    }
+
+    /// Parses the arguments that follow the program name.
+    pub fn parse(&self, args: &[String]) -> Result<Args, CliError> {
+        let mut command: Option<String> = None;
+        let mut inputs = Vec::new();
+        let mut values = HashMap::new();
+        let mut flags = Vec::new();
+
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            let (name, inline_value) = match arg.split_once('=') {
+                Some((name, value)) if name.starts_with("--") => (name, Some(value)),
+                _ => (arg.as_str(), None),
+            };
+
+            if name == "-h" || name == "--help" {
+                return Err(CliError::Help(self.usage()));
+            } else if self.options.iter().any(|&(option, _, _)| option == name) {
+                let value = match inline_value {
+                    Some(value) => value.to_string(),
+                    None => args
+                        .next()
+                        .cloned()
+                        .ok_or_else(|| self.error(format!("missing value for {}", name)))?,
+                };
+                values.insert(name.to_string(), value);
+            } else if self.flags.iter().any(|&(flag, _)| flag == name) && inline_value.is_none() {
+                flags.push(name.to_string());
+            } else if name.starts_with('-') && name != "-" {
+                return Err(self.error(format!("unknown option {}", arg)));
+            } else if !self.commands.is_empty() && command.is_none() {
+                if !self.commands.iter().any(|&(known, _)| known == arg) {
+                    return Err(self.error(format!("unknown command {}", arg)));
+                }
+                command = Some(arg.clone());
+            } else if self.inputs {
+                inputs.push(arg.clone());
+            } else {
+                return Err(self.error(format!("unexpected argument {}", arg)));
+            }
+        }
+
+        if !self.commands.is_empty() && command.is_none() {
+            return Err(self.error("missing command"));
+        }
+        if self.inputs && inputs.is_empty() {
+            return Err(self.error("missing input file"));
+        }
+
+        Ok(Args {
+            command,
+            inputs,
+            values,
+            flags,
+            usage: self.short_usage(),
+        })
+    }
+}
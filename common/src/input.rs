@@ -0,0 +1,121 @@
+use std::{
+    error::Error,
+    fmt, fs,
+    io::{self, Read},
+};
+
+use crate::LineNumbers;
+
+/// Something `normalize` had to fix for the parsers to see a clean input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Change {
+    ByteOrderMark,
+    CrLf(Vec<usize>),
+    TrailingWhitespace(Vec<usize>),
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Change::ByteOrderMark => write!(f, "UTF-8 byte order mark"),
+            Change::CrLf(lines) => write!(f, "CRLF line endings on {}", LineNumbers(lines)),
+            Change::TrailingWhitespace(lines) => {
+                write!(f, "trailing whitespace on {}", LineNumbers(lines))
+            }
+        }
+    }
+}
+
+/// The input changed by normalization while in strict mode.
+#[derive(Debug)]
+pub struct NotNormalized {
+    pub path: String,
+    pub changes: Vec<Change>,
+}
+
+impl fmt::Display for NotNormalized {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} would need normalizing:", self.path)?;
+        for change in &self.changes {
+            write!(f, "\n  {}", change)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Error for NotNormalized {}
+
+/// Drops a byte order mark, turns CRLF into LF and trims the end of every
+/// line, so parsers only ever deal with clean `\n` separated lines. Returns
+/// what had to be changed.
+pub fn normalize(input: &str) -> (String, Vec<Change>) {
+    let mut changes = Vec::new();
+    let input = match input.strip_prefix('\u{feff}') {
+        Some(input) => {
+            changes.push(Change::ByteOrderMark);
+            input
+        }
+        None => input,
+    };
+
+    let mut normalized = String::with_capacity(input.len());
+    let mut crlf = Vec::new();
+    let mut trailing_whitespace = Vec::new();
+    for (index, line) in input.split_inclusive('\n').enumerate() {
+        let (line, newline) = match line.strip_suffix('\n') {
+            Some(line) => (line, "\n"),
+            None => (line, ""),
+        };
+        let line = match line.strip_suffix('\r') {
+            Some(line) if !newline.is_empty() => {
+                crlf.push(index + 1);
+                line
+            }
+            _ => line,
+        };
+
+        let trimmed = line.trim_end();
+        if trimmed.len() != line.len() {
+            trailing_whitespace.push(index + 1);
+        }
+
+        normalized.push_str(trimmed);
+        normalized.push_str(newline);
+    }
+
+    if !crlf.is_empty() {
+        changes.push(Change::CrLf(crlf));
+    }
+    if !trailing_whitespace.is_empty() {
+        changes.push(Change::TrailingWhitespace(trailing_whitespace));
+    }
+
+    (normalized, changes)
+}
+
+/// Reads a puzzle input from `path`, or from stdin when it is `-`, and
+/// normalizes it. In `strict` mode an input that needs normalizing is an
+/// error listing what is wrong with it. Errors name the path since binaries
+/// can take several inputs.
+pub fn read_input(path: &str, strict: bool) -> Result<String, Box<dyn Error>> {
+    let input = if path == "-" {
+        let mut input = String::new();
+        io::stdin().read_to_string(&mut input).map(|_| input)
+    } else {
+        fs::read_to_string(path)
+    };
+    let input =
+        input.map_err(|error| io::Error::new(error.kind(), format!("{}: {}", path, error)))?;
+
+    let (normalized, changes) = normalize(&input);
+    if strict && !changes.is_empty() {
+        return Err(NotNormalized {
+            path: path.to_string(),
+            changes,
+        }
+        .into());
+    }
+
+    Ok(normalized)
+}
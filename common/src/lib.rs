@@ -0,0 +1,417 @@
+use std::{env, error::Error, fmt, process::ExitCode, str::FromStr};
+
+use cli::{Cli, CliError};
+
+pub use input::read_input;
+
+pub mod cli;
+pub mod input;
+pub mod json;
+
+/// Answer of a puzzle part. Days return integers of different widths and
+/// signedness, all of them fit here.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Answer(i128);
+
+impl From<u32> for Answer {
+    fn from(value: u32) -> Self {
+        Answer(value.into())
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(value: u64) -> Self {
+        Answer(value.into())
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(value: i64) -> Self {
+        Answer(value.into())
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub fn from_number(n: u8) -> Option<Self> {
+        match n {
+            1 => Some(Part::One),
+            2 => Some(Part::Two),
+            _ => None,
+        }
+    }
+
+    pub fn number(&self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse::<u8>()
+            .ok()
+            .and_then(Part::from_number)
+            .ok_or_else(|| format!("unknown part {}", s))
+    }
+}
+
+/// Where a parse error happened. Lines and columns start at 1 and the
+/// column counts bytes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Location {
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+}
+
+impl Location {
+    pub fn new(line: usize, column: usize, text: &str) -> Self {
+        Location {
+            line,
+            column,
+            text: text.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}, column {}", self.line, self.column)
+    }
+}
+
+/// Displays line numbers for a report, only the first few of them when
+/// there are many: `lines 2, 5 and 10 more`. `{:#}` lists them all.
+pub struct LineNumbers<'a>(pub &'a [usize]);
+
+impl fmt::Display for LineNumbers<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const SHOWN: usize = 5;
+
+        let lines = self.0;
+        write!(f, "{} ", if lines.len() == 1 { "line" } else { "lines" })?;
+
+        let shown = if f.alternate() { lines.len() } else { SHOWN };
+        let listed = lines
+            .iter()
+            .take(shown)
+            .map(usize::to_string)
+            .collect::<Vec<String>>();
+        write!(f, "{}", listed.join(", "))?;
+        if lines.len() > shown {
+            write!(f, " and {} more", lines.len() - shown)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Same as `input.split(separator)` but also yields the byte offset where
+/// every piece starts, so parsers can report columns.
+pub fn split_with_offsets(
+    input: &str,
+    separator: char,
+) -> impl Iterator<Item = (usize, &str)> + '_ {
+    input.split(separator).scan(0, move |offset, piece| {
+        let start = *offset;
+        *offset += piece.len() + separator.len_utf8();
+        Some((start, piece))
+    })
+}
+
+/// One item of the input and what it adds to the answer, e.g. the
+/// calibration value of a line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Detail {
+    pub line: usize,
+    pub value: Answer,
+}
+
+impl Detail {
+    pub fn new(line: usize, value: impl Into<Answer>) -> Self {
+        Detail {
+            line,
+            value: value.into(),
+        }
+    }
+}
+
+/// A day of the calendar. `parse` turns the raw puzzle input into whatever
+/// both parts work on, so it only has to be done once.
+pub trait Solution {
+    const DAY: u8;
+
+    /// Whether `details` breaks the answers down.
+    const HAS_DETAILS: bool = false;
+
+    type Input<'a>;
+
+    type Error: Error + 'static;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Self::Error>;
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer, Self::Error>;
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer, Self::Error>;
+
+    fn solve(input: &Self::Input<'_>, part: Part) -> Result<Answer, Self::Error> {
+        match part {
+            Part::One => Self::part1(input),
+            Part::Two => Self::part2(input),
+        }
+    }
+
+    /// Breakdown of the answer per input item, days that can't tell which
+    /// line added what return nothing and leave `HAS_DETAILS` false.
+    fn details(_input: &Self::Input<'_>, _part: Part) -> Result<Vec<Detail>, Self::Error> {
+        Ok(Vec::new())
+    }
+}
+
+/// 64-bit FNV-1a, stable across builds so it can identify an input file in
+/// reports.
+pub fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf29ce484222325, |hash, &byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x100000001b3)
+    })
+}
+
+/// What goes before an answer so the ones of several inputs can be told
+/// apart, nothing when there is a single input.
+pub fn input_label(path: &str, inputs: usize) -> String {
+    if inputs > 1 {
+        format!("{}: ", path)
+    } else {
+        String::new()
+    }
+}
+
+/// Turns the outcome of a binary into its exit code, printing the error
+/// instead of the `Debug` dump `main` would show. Invalid invocations exit
+/// with 2, `--help` isn't an error at all.
+pub fn exit_code(result: Result<(), Box<dyn Error>>) -> ExitCode {
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => match error.downcast_ref::<CliError>() {
+            Some(CliError::Help(usage)) => {
+                println!("{}", usage);
+                ExitCode::SUCCESS
+            }
+            Some(CliError::Usage { .. }) => {
+                eprintln!("error: {}", error);
+                ExitCode::from(2)
+            }
+            None => {
+                eprintln!("error: {}", error);
+                ExitCode::FAILURE
+            }
+        },
+    }
+}
+
+/// The whole `main` of a day binary: `part1` and `part2` commands over any
+/// number of inputs, printing `total <answer>` for each, so every day takes
+/// the same command line and exits the same way.
+pub fn run_day<S: Solution>(
+    name: &'static str,
+    about: &'static str,
+    part1_help: &'static str,
+    part2_help: &'static str,
+) -> ExitCode {
+    let args: Vec<String> = env::args().collect();
+    let cli = Cli::new(name, about)
+        .command("part1", part1_help)
+        .command("part2", part2_help)
+        .inputs();
+
+    exit_code(solve_inputs::<S>(&cli, &args[1..]))
+}
+
+fn solve_inputs<S: Solution>(cli: &Cli, args: &[String]) -> Result<(), Box<dyn Error>> {
+    let args = cli.parse(args)?;
+    let part = match args.command.as_deref() {
+        Some("part1") => Part::One,
+        Some("part2") => Part::Two,
+        _ => unreachable!("the command line only takes part1 and part2"),
+    };
+
+    for path in &args.inputs {
+        let input = read_input(path, args.flag("--strict"))?;
+        let total = S::parse(&input)
+            .and_then(|input| S::solve(&input, part))
+            .map_err(|error| format!("{}: {}", path, error))?;
+
+        println!("{}total {}", input_label(path, args.inputs.len()), total);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::Infallible;
+
+    use super::*;
+
+    struct Lines;
+
+    impl Solution for Lines {
+        const DAY: u8 = 0;
+
+        type Input<'a> = &'a str;
+
+        type Error = Infallible;
+
+        fn parse(input: &str) -> Result<Self::Input<'_>, Self::Error> {
+            Ok(input)
+        }
+
+        fn part1(input: &Self::Input<'_>) -> Result<Answer, Self::Error> {
+            Ok((input.lines().count() as u64).into())
+        }
+
+        fn part2(input: &Self::Input<'_>) -> Result<Answer, Self::Error> {
+            Ok((input.len() as u64).into())
+        }
+    }
+
+    #[test]
+    fn test_line_numbers_are_all_listed_on_demand() {
+        let lines = [1, 2, 3, 4, 5, 6, 7];
+
+        assert_eq!("line 3", LineNumbers(&[3]).to_string());
+        assert_eq!(
+            "lines 1, 2, 3, 4, 5 and 2 more",
+            LineNumbers(&lines).to_string()
+        );
+        assert_eq!(
+            "lines 1, 2, 3, 4, 5, 6, 7",
+            format!("{:#}", LineNumbers(&lines))
+        );
+    }
+
+    #[test]
+    fn test_solve_inputs_takes_the_day_command_line() {
+        let cli = Cli::new("lines", "Counts lines")
+            .command("part1", "lines")
+            .command("part2", "bytes")
+            .inputs();
+        let args = |args: &[&str]| args.iter().map(|arg| arg.to_string()).collect::<Vec<_>>();
+
+        let error = solve_inputs::<Lines>(&cli, &args(&["part3", "x"])).unwrap_err();
+        assert!(matches!(
+            error.downcast_ref::<CliError>(),
+            Some(CliError::Usage { .. })
+        ));
+
+        let error = solve_inputs::<Lines>(&cli, &args(&["part1", "/nonexistent"])).unwrap_err();
+        assert!(error.to_string().starts_with("/nonexistent: "));
+    }
+
+    #[test]
+    fn test_answer_keeps_the_sign_and_width_of_the_original_value() {
+        assert_eq!("-2", Answer::from(-2i64).to_string());
+        assert_eq!("84289137", Answer::from(84289137u32).to_string());
+        assert_eq!(u64::MAX.to_string(), Answer::from(u64::MAX).to_string());
+    }
+
+    #[test]
+    fn test_split_with_offsets() {
+        let pieces = split_with_offsets("41 48  6", ' ').collect::<Vec<_>>();
+
+        assert_eq!(vec![(0, "41"), (3, "48"), (6, ""), (7, "6")], pieces);
+    }
+
+    #[test]
+    fn test_part_from_number() {
+        assert_eq!(Some(Part::One), Part::from_number(1));
+        assert_eq!(Some(Part::Two), Part::from_number(2));
+        assert_eq!(None, Part::from_number(3));
+    }
+
+    #[test]
+    fn test_cli_parses_commands_options_and_inputs() {
+        let cli = cli::Cli::new("day3", "Gear Ratios")
+            .command("part1", "sum of the part numbers")
+            .option("--bag", "<cubes>", "cubes in the bag")
+            .flag("--details", "print every line")
+            .inputs();
+        let args = |args: &[&str]| args.iter().map(|arg| arg.to_string()).collect::<Vec<_>>();
+
+        let parsed = cli
+            .parse(&args(&["part1", "--bag=red=2", "--details", "-", "a.txt"]))
+            .unwrap();
+        assert_eq!(Some("part1"), parsed.command.as_deref());
+        assert_eq!(Some("red=2"), parsed.value("--bag"));
+        assert!(parsed.flag("--details"));
+        assert_eq!(vec!["-", "a.txt"], parsed.inputs);
+
+        assert!(matches!(
+            cli.parse(&args(&["part1", "-h"])),
+            Err(CliError::Help(_))
+        ));
+        for invalid in [
+            &["part3", "a.txt"][..],
+            &["part1"],
+            &["part1", "--bag"],
+            &["--x"],
+        ] {
+            assert!(matches!(
+                cli.parse(&args(invalid)),
+                Err(CliError::Usage { .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn test_normalize_reports_every_change() {
+        let (normalized, changes) = input::normalize("\u{feff}Time: 7 \r\nDistance: 9\r\n\n1 2\t");
+
+        assert_eq!("Time: 7\nDistance: 9\n\n1 2", normalized);
+        assert_eq!(
+            vec![
+                input::Change::ByteOrderMark,
+                input::Change::CrLf(vec![1, 2]),
+                input::Change::TrailingWhitespace(vec![1, 4]),
+            ],
+            changes
+        );
+        assert_eq!(("0 3 6\n".to_string(), vec![]), input::normalize("0 3 6\n"));
+    }
+
+    #[test]
+    fn test_fnv1a() {
+        assert_eq!(0xcbf29ce484222325, fnv1a(b""));
+        assert_eq!(0xaf63dc4c8601ec8c, fnv1a(b"a"));
+    }
+
+    #[test]
+    fn test_json_escapes_strings_and_keeps_answers_exact() {
+        let report = json::Json::object([
+            ("input", json::Json::from("day1/\"input\".txt\n")),
+            ("answer", Answer::from(u64::MAX).into()),
+            ("timings", vec![1u64, 2].into()),
+        ]);
+
+        assert_eq!(
+            r#"{"input":"day1/\"input\".txt\n","answer":18446744073709551615,"timings":[1,2]}"#,
+            report.to_string()
+        );
+    }
+}
@@ -0,0 +1,154 @@
+//! Compares the scanner behind `find_calibration_values` with the original
+//! search, which tried every word at every position, on a generated input of
+//! several megabytes. Run with `cargo bench -p day1`.
+
+use std::{hint::black_box, time::Instant};
+
+use day1::find_calibration_values;
+
+const INPUT_LINES: usize = 200_000;
+
+// the original search, kept as the baseline
+const MAX_CHARACTERS_ALLOWED: usize = 5;
+
+fn map_word_to_digit(word: &str) -> Option<u32> {
+    match word {
+        "one" => Some(1),
+        "two" => Some(2),
+        "three" => Some(3),
+        "four" => Some(4),
+        "five" => Some(5),
+        "six" => Some(6),
+        "seven" => Some(7),
+        "eight" => Some(8),
+        "nine" => Some(9),
+        _ => None,
+    }
+}
+
+fn find_first_digit(input: &str) -> u32 {
+    let mut substring = String::new();
+    let mut digit = 0;
+    for character in input.chars() {
+        if let Some(d) = character.to_digit(10) {
+            digit = d;
+            break;
+        }
+
+        substring.push(character);
+    }
+
+    let mut buff = String::new();
+    for i in 0..substring.len() {
+        for j in 0..MAX_CHARACTERS_ALLOWED {
+            if let Some(character) = substring.chars().nth(i + j) {
+                buff.push(character);
+                if let Some(digit) = map_word_to_digit(&buff) {
+                    return digit;
+                }
+            }
+        }
+
+        buff.clear();
+    }
+
+    digit
+}
+
+fn find_last_digit(input: &str) -> u32 {
+    let mut substring = String::new();
+    let mut digit = 0;
+    for character in input.chars().rev() {
+        if let Some(d) = character.to_digit(10) {
+            digit = d;
+            break;
+        }
+
+        substring = format!("{}{}", character, substring);
+    }
+
+    if substring.is_empty() {
+        return digit;
+    }
+
+    let mut buff = String::new();
+    for i in (0..substring.len()).rev() {
+        for j in 0..MAX_CHARACTERS_ALLOWED {
+            if j > i {
+                continue;
+            }
+
+            if let Some(character) = substring.chars().nth(i - j) {
+                buff = format!("{}{}", character, buff);
+
+                if let Some(digit) = map_word_to_digit(&buff) {
+                    return digit;
+                }
+            }
+        }
+
+        buff.clear();
+    }
+
+    digit
+}
+
+fn find_calibration_values_naive(input: &str) -> Vec<u32> {
+    input
+        .lines()
+        .map(|line| find_first_digit(line) * 10 + find_last_digit(line))
+        .collect::<Vec<u32>>()
+}
+
+/// Lines of random letters with the odd digit, deterministic so runs
+/// compare.
+fn generate_input() -> String {
+    const PIECES: [&str; 13] = [
+        "one", "two", "three", "four", "five", "six", "seven", "eight", "nine", "x", "qz", "7",
+        "kl",
+    ];
+
+    let mut seed: u64 = 0x2023_1201;
+    let mut next = move || {
+        seed ^= seed << 13;
+        seed ^= seed >> 7;
+        seed ^= seed << 17;
+        seed
+    };
+
+    let mut input = String::new();
+    for _ in 0..INPUT_LINES {
+        let pieces = 4 + next() % 12;
+        for _ in 0..pieces {
+            input.push_str(PIECES[(next() % PIECES.len() as u64) as usize]);
+        }
+        input.push('\n');
+    }
+
+    input
+}
+
+fn time(name: &str, input: &str, solve: fn(&str) -> Vec<u32>) -> u32 {
+    let start = Instant::now();
+    let total = black_box(solve(black_box(input))).iter().sum::<u32>();
+    let elapsed = start.elapsed();
+
+    let megabytes = input.len() as f64 / 1_000_000.0;
+    println!(
+        "{:<8}{:>14?}{:>12.1} MB/s",
+        name,
+        elapsed,
+        megabytes / elapsed.as_secs_f64()
+    );
+
+    total
+}
+
+fn main() {
+    let input = generate_input();
+    println!("{} lines, {} bytes", INPUT_LINES, input.len());
+
+    let naive = time("naive", &input, find_calibration_values_naive);
+    let scanner = time("scanner", &input, find_calibration_values);
+    assert_eq!(naive, scanner, "both searches must agree");
+}
@@ -0,0 +1,489 @@
+use std::{
+    convert::Infallible,
+    error::Error,
+    fmt,
+    io::{self, BufRead},
+    str::FromStr,
+    sync::OnceLock,
+};
+
+use common::{Answer, Detail, LineNumbers, Part, Solution};
+use scanner::{Match, Scanner};
+use vocabulary::Vocabulary;
+
+pub mod scanner;
+pub mod vocabulary;
+
+pub fn map_word_to_digit(word: &str) -> Option<u32> {
+    Vocabulary::english().value(word)
+}
+
+/// Scanner for digits and English digit words, built once.
+fn scanner() -> &'static Scanner {
+    static SCANNER: OnceLock<Scanner> = OnceLock::new();
+    SCANNER.get_or_init(|| Vocabulary::english().scanner())
+}
+
+/// Scanner for digits alone, built once.
+fn digits_scanner() -> &'static Scanner {
+    static SCANNER: OnceLock<Scanner> = OnceLock::new();
+    SCANNER.get_or_init(|| Vocabulary::digits_only().scanner())
+}
+
+pub fn find_first_digit(input: &str) -> u32 {
+    scanner()
+        .first_and_last(input)
+        .map_or(0, |(first, _)| first.first_digit())
+}
+
+pub fn find_last_digit(input: &str) -> u32 {
+    scanner()
+        .first_and_last(input)
+        .map_or(0, |(_, last)| last.last_digit())
+}
+
+/// The leading digit of the first match and the trailing digit of the last,
+/// as if every number word had been written with digits.
+fn calibration_value(first: Match, last: Match) -> u32 {
+    first.first_digit() * 10 + last.last_digit()
+}
+
+pub fn find_calibration_values(input: &str) -> Vec<u32> {
+    find_calibration_values_with(input, scanner())
+}
+
+/// Same as `find_calibration_values` with the words of another vocabulary.
+pub fn find_calibration_values_with(input: &str, scanner: &Scanner) -> Vec<u32> {
+    input
+        .lines()
+        .map(|line| {
+            scanner
+                .first_and_last(line)
+                .map_or(0, |(first, last)| calibration_value(first, last))
+        })
+        .collect::<Vec<u32>>()
+}
+
+/// What to do with a line without any digit: fail, leave it out, or count
+/// it as `0` like the puzzle does.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum NoDigitPolicy {
+    Error,
+    Skip,
+    #[default]
+    Zero,
+}
+
+impl FromStr for NoDigitPolicy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "error" => Ok(NoDigitPolicy::Error),
+            "skip" => Ok(NoDigitPolicy::Skip),
+            "zero" => Ok(NoDigitPolicy::Zero),
+            _ => Err(format!("unknown policy {}", s)),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct NoDigitError {
+    pub lines: Vec<usize>,
+}
+
+impl fmt::Display for NoDigitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "no digit on {:#}", LineNumbers(&self.lines))
+    }
+}
+
+impl Error for NoDigitError {}
+
+/// The calibration value of every line by line number, and the lines that
+/// had no digit.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Calibration {
+    pub values: Vec<(usize, u32)>,
+    pub lines_without_digits: Vec<usize>,
+}
+
+impl Calibration {
+    pub fn total(&self) -> u32 {
+        self.values.iter().map(|&(_, value)| value).sum()
+    }
+}
+
+pub fn calibrate(
+    input: &str,
+    scanner: &Scanner,
+    policy: NoDigitPolicy,
+) -> Result<Calibration, NoDigitError> {
+    let mut values = Vec::new();
+    let mut lines_without_digits = Vec::new();
+    for (index, line) in input.lines().enumerate() {
+        let line_number = index + 1;
+        match scanner.first_and_last(line) {
+            Some((first, last)) => values.push((line_number, calibration_value(first, last))),
+            None => {
+                lines_without_digits.push(line_number);
+                if policy == NoDigitPolicy::Zero {
+                    values.push((line_number, 0));
+                }
+            }
+        }
+    }
+
+    if policy == NoDigitPolicy::Error && !lines_without_digits.is_empty() {
+        return Err(NoDigitError {
+            lines: lines_without_digits,
+        });
+    }
+
+    Ok(Calibration {
+        values,
+        lines_without_digits,
+    })
+}
+
+/// What `calibrate_reader` found, counts instead of lists so that memory
+/// stays the same whatever the size of the input.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct StreamedCalibration {
+    pub total: u64,
+    pub lines: u64,
+    pub lines_without_digits: u64,
+}
+
+#[derive(Debug)]
+pub enum StreamError {
+    Io(io::Error),
+    NoDigit(NoDigitError),
+}
+
+impl fmt::Display for StreamError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StreamError::Io(error) => write!(f, "{}", error),
+            StreamError::NoDigit(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl Error for StreamError {}
+
+impl From<io::Error> for StreamError {
+    fn from(error: io::Error) -> Self {
+        StreamError::Io(error)
+    }
+}
+
+/// `calibrate` one line at a time from `reader`, for inputs too big to be
+/// read at once. Lines are normalized like `read_input` does, and with
+/// `NoDigitPolicy::Error` it stops at the first line without a digit.
+pub fn calibrate_reader(
+    mut reader: impl BufRead,
+    scanner: &Scanner,
+    policy: NoDigitPolicy,
+) -> Result<StreamedCalibration, StreamError> {
+    let mut calibration = StreamedCalibration::default();
+    let mut buffer = String::new();
+    loop {
+        buffer.clear();
+        if reader.read_line(&mut buffer)? == 0 {
+            return Ok(calibration);
+        }
+
+        calibration.lines += 1;
+        let mut line = buffer.trim_end();
+        if calibration.lines == 1 {
+            line = line.strip_prefix('\u{feff}').unwrap_or(line);
+        }
+
+        match scanner.first_and_last(line) {
+            Some((first, last)) => calibration.total += u64::from(calibration_value(first, last)),
+            None if policy == NoDigitPolicy::Error => {
+                return Err(StreamError::NoDigit(NoDigitError {
+                    lines: vec![calibration.lines as usize],
+                }));
+            }
+            None => calibration.lines_without_digits += 1,
+        }
+    }
+}
+
+/// How the calibration value of a line was found.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Explanation<'a> {
+    pub line_number: usize,
+    pub line: &'a str,
+    pub first_and_last: Option<(Match, Match)>,
+}
+
+impl Explanation<'_> {
+    pub fn calibration_value(&self) -> u32 {
+        self.first_and_last
+            .map_or(0, |(first, last)| calibration_value(first, last))
+    }
+}
+
+impl fmt::Display for Explanation<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "line {}: {}", self.line_number, self.calibration_value())?;
+        let Some((first, last)) = self.first_and_last else {
+            return write!(f, "  no digit in `{}`", self.line);
+        };
+
+        for (name, found, digit) in [
+            ("first", first, first.first_digit()),
+            ("last", last, last.last_digit()),
+        ] {
+            write!(
+                f,
+                "  {:<6}{:<6}`{}` at bytes {}..{}",
+                name,
+                found.kind,
+                &self.line[found.start..found.end],
+                found.start,
+                found.end
+            )?;
+            if found.value >= 10 {
+                write!(f, ", {} gives {}", found.value, digit)?;
+            }
+            writeln!(f)?;
+        }
+
+        // one mark under every character of the two matches
+        let marks = self
+            .line
+            .char_indices()
+            .map(|(index, _)| {
+                let marked = [first, last]
+                    .iter()
+                    .any(|found| (found.start..found.end).contains(&index));
+                if marked {
+                    '^'
+                } else {
+                    ' '
+                }
+            })
+            .collect::<String>();
+        write!(f, "  {}\n  {}", self.line, marks.trim_end())
+    }
+}
+
+pub fn explain_calibration_values<'a>(input: &'a str, scanner: &Scanner) -> Vec<Explanation<'a>> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| Explanation {
+            line_number: index + 1,
+            line,
+            first_and_last: scanner.first_and_last(line),
+        })
+        .collect::<Vec<Explanation>>()
+}
+
+pub fn find_calibration_values_only_digits(input: &str) -> Vec<u32> {
+    find_calibration_values_with(input, digits_scanner())
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+
+    const HAS_DETAILS: bool = true;
+
+    type Input<'a> = &'a str;
+
+    // every line is a valid calibration line, at worst it has no digits
+    type Error = Infallible;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Self::Error> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer, Self::Error> {
+        Ok(find_calibration_values_only_digits(input)
+            .iter()
+            .sum::<u32>()
+            .into())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer, Self::Error> {
+        Ok(find_calibration_values(input).iter().sum::<u32>().into())
+    }
+
+    fn details(input: &Self::Input<'_>, part: Part) -> Result<Vec<Detail>, Self::Error> {
+        let calibration_values = match part {
+            Part::One => find_calibration_values_only_digits(input),
+            Part::Two => find_calibration_values(input),
+        };
+
+        Ok(calibration_values
+            .into_iter()
+            .enumerate()
+            .map(|(index, value)| Detail::new(index + 1, value))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_first_and_last_digits_when_string_has_random_letters() {
+        let input = "1abc2";
+        assert_eq!(1, find_first_digit(input));
+        assert_eq!(2, find_last_digit(input));
+
+        let input = "pqr3stu8vwx";
+        assert_eq!(3, find_first_digit(input));
+        assert_eq!(8, find_last_digit(input));
+
+        let input = "a1b2c3d4e5f";
+        assert_eq!(1, find_first_digit(input));
+        assert_eq!(5, find_last_digit(input));
+
+        let input = "treb7uchet";
+        assert_eq!(7, find_first_digit(input));
+        assert_eq!(7, find_last_digit(input));
+    }
+
+    #[test]
+    fn test_find_first_and_last_digits_when_string_has_digits_as_words_and_digits() {
+        let input = "two1nine";
+        assert_eq!(2, find_first_digit(input));
+        assert_eq!(9, find_last_digit(input));
+
+        let input = "eightwothree";
+        assert_eq!(8, find_first_digit(input));
+        assert_eq!(3, find_last_digit(input));
+
+        let input = "abcone2threexyz";
+        assert_eq!(1, find_first_digit(input));
+        assert_eq!(3, find_last_digit(input));
+
+        let input = "xtwone3four";
+        assert_eq!(2, find_first_digit(input));
+        assert_eq!(4, find_last_digit(input));
+
+        let input = "4nineeightseven2";
+        assert_eq!(4, find_first_digit(input));
+        assert_eq!(2, find_last_digit(input));
+
+        let input = "zoneight234";
+        assert_eq!(1, find_first_digit(input));
+        assert_eq!(4, find_last_digit(input));
+
+        let input = "7pqrstsixteen";
+        assert_eq!(7, find_first_digit(input));
+        assert_eq!(6, find_last_digit(input));
+    }
+
+    #[test]
+    fn test_find_calibration_values_should_return_all_digits_when_a_line_only_contains_digits_and_random_letter(
+    ) {
+        let input = "1abc2\npqr3stu8vwx\na1b2c3d4e5f\ntreb7uchet";
+        let expected_sum: u32 = 142;
+
+        let calibration_values = find_calibration_values(input);
+
+        assert_eq!(expected_sum, calibration_values.iter().sum());
+    }
+
+    #[test]
+    fn test_find_calibration_values_only_digits_should_ignore_digits_encoded_as_words() {
+        let input = "1abc2\npqr3stu8vwx\na1b2c3d4e5f\ntreb7uchet\ntwo1nine\neightwothree";
+        let expected_sum: u32 = 142 + 11;
+
+        let calibration_values = find_calibration_values_only_digits(input);
+
+        assert_eq!(expected_sum, calibration_values.iter().sum());
+    }
+
+    #[test]
+    fn test_calibrate_applies_the_no_digit_policy() {
+        let input = "two1nine\nabc\n7pqr\nxyz";
+
+        let calibration = calibrate(input, scanner(), NoDigitPolicy::Zero).unwrap();
+        assert_eq!(vec![(1, 29), (2, 0), (3, 77), (4, 0)], calibration.values);
+        assert_eq!(vec![2, 4], calibration.lines_without_digits);
+
+        let calibration = calibrate(input, scanner(), NoDigitPolicy::Skip).unwrap();
+        assert_eq!(vec![(1, 29), (3, 77)], calibration.values);
+        assert_eq!(106, calibration.total());
+
+        let error = NoDigitError { lines: vec![2, 4] };
+        assert_eq!(
+            Err(error),
+            calibrate(input, scanner(), NoDigitPolicy::Error)
+        );
+
+        let error = calibrate(&"x\n".repeat(7), scanner(), NoDigitPolicy::Error).unwrap_err();
+        assert_eq!("no digit on lines 1, 2, 3, 4, 5, 6, 7", error.to_string());
+    }
+
+    #[test]
+    fn test_calibrate_reader_streams_the_lines() {
+        let input = "\u{feff}two1nine\r\nabc  \n7pqr\n";
+
+        let calibration =
+            calibrate_reader(input.as_bytes(), scanner(), NoDigitPolicy::Zero).unwrap();
+        assert_eq!(
+            StreamedCalibration {
+                total: 106,
+                lines: 3,
+                lines_without_digits: 1
+            },
+            calibration
+        );
+
+        let error =
+            calibrate_reader(input.as_bytes(), scanner(), NoDigitPolicy::Error).unwrap_err();
+        assert_eq!("no digit on line 2", error.to_string());
+    }
+
+    #[test]
+    fn test_explain_calibration_values_shows_the_matches() {
+        let explanations = explain_calibration_values("xtwone3four\nabc", scanner());
+
+        assert_eq!(24, explanations[0].calibration_value());
+        assert_eq!(
+            "line 1: 24
+  first word  `two` at bytes 1..4
+  last  word  `four` at bytes 7..11
+  xtwone3four
+   ^^^   ^^^^",
+            explanations[0].to_string()
+        );
+        assert_eq!(
+            "line 2: 0\n  no digit in `abc`",
+            explanations[1].to_string()
+        );
+
+        let scanner = Vocabulary::english_numbers().scanner();
+        let explanations = explain_calibration_values("twelve3", &scanner);
+        assert_eq!(
+            "line 1: 13
+  first word  `twelve` at bytes 0..6, 12 gives 1
+  last  digit `3` at bytes 6..7
+  twelve3
+  ^^^^^^^",
+            explanations[0].to_string()
+        );
+    }
+
+    #[test]
+    fn test_find_calibration_values_should_return_all_digits_when_a_line_contains_digits_and_digits_encoded_as_words(
+    ) {
+        let input = "two1nine\neightwothree\nabcone2threexyz\nxtwone3four\n4nineeightseven2\nzoneight234\n7pqrstsixteen";
+        let expected_sum: u32 = 281;
+
+        let calibration_values = find_calibration_values(input);
+
+        assert_eq!(expected_sum, calibration_values.iter().sum());
+    }
+}
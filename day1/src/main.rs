@@ -1,185 +1,132 @@
-use std::{env, error::Error, fs};
-
-const MAX_CHARACTERS_ALLOWED: usize = 5;
-
-fn map_word_to_digit(word: &str) -> Option<u32> {
-    match word {
-        "one" => Some(1),
-        "two" => Some(2),
-        "three" => Some(3),
-        "four" => Some(4),
-        "five" => Some(5),
-        "six" => Some(6),
-        "seven" => Some(7),
-        "eight" => Some(8),
-        "nine" => Some(9),
-        _ => None,
+use std::{
+    env,
+    error::Error,
+    fs::File,
+    io::{self, BufRead, BufReader},
+    process::ExitCode,
+};
+
+use common::{cli::Cli, exit_code, input_label, read_input, LineNumbers};
+use day1::{
+    calibrate, calibrate_reader, explain_calibration_values, scanner::Scanner,
+    vocabulary::Vocabulary, NoDigitPolicy,
+};
+
+fn run() -> Result<(), Box<dyn Error>> {
+    let args: Vec<String> = env::args().collect();
+    let args = Cli::new("day1", "Day 1: Trebuchet?!")
+        .command("part1", "sum of the calibration values, digits only")
+        .command(
+            "part2",
+            "sum of the calibration values, digits and digit words",
+        )
+        .option(
+            "--words",
+            "<name|file>",
+            "number words of part2: english (default), english-numbers, spanish, german or a file",
+        )
+        .flag(
+            "--unicode-digits",
+            "also count non-ASCII decimal digits such as ３ or ٣",
+        )
+        .option(
+            "--no-digits",
+            "<error|skip|zero>",
+            "what to do with lines without digits, zero by default",
+        )
+        .flag("--explain", "show which digits were picked on every line")
+        .flag(
+            "--stream",
+            "read the inputs a line at a time, for huge ones",
+        )
+        .inputs()
+        .parse(&args[1..])?;
+
+    let part1 = args.command.as_deref() == Some("part1");
+    let vocabulary = match args.value("--words") {
+        None if part1 => Vocabulary::digits_only(),
+        None => Vocabulary::english(),
+        Some(_) if part1 => return Err(args.error("part1 doesn't take --words").into()),
+        Some(name) => match Vocabulary::builtin(name) {
+            Some(vocabulary) => vocabulary,
+            None => Vocabulary::parse(&read_input(name, false)?)
+                .map_err(|error| format!("{}: {}", name, error))?,
+        },
+    };
+    let mut scanner = vocabulary.scanner();
+    if args.flag("--unicode-digits") {
+        scanner = scanner.with_unicode_digits();
     }
-}
 
-fn find_first_digit(input: &str) -> u32 {
-    let mut substring = String::new();
-    let mut digit = 0;
-    for character in input.chars() {
-        if let Some(d) = character.to_digit(10) {
-            digit = d;
-            break;
-        }
+    let policy = args
+        .parse::<NoDigitPolicy>("--no-digits")?
+        .unwrap_or_default();
 
-        substring.push(character);
-    }
-
-    let mut buff = String::new();
-    for i in 0..substring.len() {
-        for j in 0..MAX_CHARACTERS_ALLOWED {
-            if let Some(character) = substring.chars().nth(i + j) {
-                buff.push(character);
-                if let Some(digit) = map_word_to_digit(&buff) {
-                    return digit;
-                }
-            }
+    let paths = &args.inputs;
+    if args.flag("--stream") {
+        if args.flag("--explain") || args.flag("--strict") {
+            return Err(args
+                .error("--stream can't be used with --explain or --strict")
+                .into());
         }
 
-        buff.clear();
-    }
-
-    digit
-}
-
-fn find_last_digit(input: &str) -> u32 {
-    let mut substring = String::new();
-    let mut digit = 0;
-    for character in input.chars().rev() {
-        if let Some(d) = character.to_digit(10) {
-            digit = d;
-            break;
+        for path in paths {
+            stream(path, input_label(path, paths.len()), &scanner, policy)?;
         }
 
-        substring = format!("{}{}", character, substring);
-    }
-
-    if substring.len() < 1 {
-        return digit
+        return Ok(());
     }
 
-    let mut buff = String::new();
-    for i in (0..=substring.len() - 1).rev() {
-        for j in 0..MAX_CHARACTERS_ALLOWED {
-            if j > i {
-                continue;
+    for path in paths {
+        let input = read_input(path, args.flag("--strict"))?;
+        if args.flag("--explain") {
+            for explanation in explain_calibration_values(&input, &scanner) {
+                println!("{}", explanation);
             }
+        }
 
-            if let Some(character) = substring.chars().nth(i - j) {
-                buff = format!("{}{}", character, buff);
-
-                if let Some(digit) = map_word_to_digit(&buff) {
-                    return digit;
-                }
-            }
+        let label = input_label(path, paths.len());
+        let calibration =
+            calibrate(&input, &scanner, policy).map_err(|error| format!("{}: {}", path, error))?;
+        if !calibration.lines_without_digits.is_empty() {
+            let lines = LineNumbers(&calibration.lines_without_digits);
+            eprintln!("warning: {}no digit on {:#}", label, lines);
         }
 
-        buff.clear();
+        println!("{}Total {}", label, calibration.total())
     }
 
-    digit
-}
-
-fn find_calibration_values(input: &str) -> Vec<u32> {
-    input
-        .lines()
-        .map(|line| {
-            let first = find_first_digit(line);
-            let last = find_last_digit(line);
-            first * 10 + last
-        })
-        .collect::<Vec<u32>>()
+    Ok(())
 }
 
-fn main() -> Result<(), Box<dyn Error>> {
-    let args: Vec<String> = env::args().collect();
-    if args.len() > 1 {
-        let input = fs::read_to_string(&args[1])?;
-        let total: u32 = find_calibration_values(&input).iter().sum();
-
-        println!("Total {}", total)
+/// `run` for one input read a line at a time, which only reports how many
+/// lines had no digit.
+fn stream(
+    path: &str,
+    label: String,
+    scanner: &Scanner,
+    policy: NoDigitPolicy,
+) -> Result<(), Box<dyn Error>> {
+    let reader: Box<dyn BufRead> = if path == "-" {
+        Box::new(io::stdin().lock())
+    } else {
+        let file = File::open(path).map_err(|error| format!("{}: {}", path, error))?;
+        Box::new(BufReader::new(file))
+    };
+
+    let calibration = calibrate_reader(reader, scanner, policy)
+        .map_err(|error| format!("{}: {}", path, error))?;
+    if calibration.lines_without_digits > 0 {
+        eprintln!(
+            "warning: {}no digit on {} of {} lines",
+            label, calibration.lines_without_digits, calibration.lines
+        );
     }
 
+    println!("{}Total {}", label, calibration.total);
     Ok(())
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_find_first_and_last_digits_when_string_has_random_letters() {
-        let input = "1abc2";
-        assert_eq!(1, find_first_digit(input));
-        assert_eq!(2, find_last_digit(input));
-
-        let input = "pqr3stu8vwx";
-        assert_eq!(3, find_first_digit(input));
-        assert_eq!(8, find_last_digit(input));
-
-        let input = "a1b2c3d4e5f";
-        assert_eq!(1, find_first_digit(input));
-        assert_eq!(5, find_last_digit(input));
-
-        let input = "treb7uchet";
-        assert_eq!(7, find_first_digit(input));
-        assert_eq!(7, find_last_digit(input));
-    }
-
-    #[test]
-    fn test_find_first_and_last_digits_when_string_has_digits_as_words_and_digits() {
-        let input = "two1nine";
-        assert_eq!(2, find_first_digit(input));
-        assert_eq!(9, find_last_digit(input));
-
-        let input = "eightwothree";
-        assert_eq!(8, find_first_digit(input));
-        assert_eq!(3, find_last_digit(input));
-
-        let input = "abcone2threexyz";
-        assert_eq!(1, find_first_digit(input));
-        assert_eq!(3, find_last_digit(input));
-
-        let input = "xtwone3four";
-        assert_eq!(2, find_first_digit(input));
-        assert_eq!(4, find_last_digit(input));
-
-        let input = "4nineeightseven2";
-        assert_eq!(4, find_first_digit(input));
-        assert_eq!(2, find_last_digit(input));
-
-        let input = "zoneight234";
-        assert_eq!(1, find_first_digit(input));
-        assert_eq!(4, find_last_digit(input));
-
-        let input = "7pqrstsixteen";
-        assert_eq!(7, find_first_digit(input));
-        assert_eq!(6, find_last_digit(input));
-    }
-
-    #[test]
-    fn test_find_calibration_values_should_return_all_digits_when_a_line_only_contains_digits_and_random_letter(
-    ) {
-        let input = "1abc2\npqr3stu8vwx\na1b2c3d4e5f\ntreb7uchet";
-        let expected_sum: u32 = 142;
-
-        let calibration_values = find_calibration_values(input);
-
-        assert_eq!(expected_sum, calibration_values.iter().sum());
-    }
-
-    #[test]
-    fn test_find_calibration_values_should_return_all_digits_when_a_line_contains_digits_and_digits_encoded_as_words(
-    ) {
-        let input = "two1nine\neightwothree\nabcone2threexyz\nxtwone3four\n4nineeightseven2\nzoneight234\n7pqrstsixteen";
-        let expected_sum: u32 = 281;
-
-        let calibration_values = find_calibration_values(input);
-
-        assert_eq!(expected_sum, calibration_values.iter().sum());
-    }
+fn main() -> ExitCode {
+    exit_code(run())
 }
@@ -0,0 +1,258 @@
+use std::{cmp::Reverse, collections::VecDeque, fmt};
+
+/// The zero of every block of Unicode decimal digits (general category
+/// `Nd`, as of Unicode 15). Every block holds the digits 0 to 9 in order.
+const DECIMAL_ZEROS: [u32; 68] = [
+    0x0030, 0x0660, 0x06F0, 0x07C0, 0x0966, 0x09E6, 0x0A66, 0x0AE6, 0x0B66, 0x0BE6, 0x0C66, 0x0CE6,
+    0x0D66, 0x0DE6, 0x0E50, 0x0ED0, 0x0F20, 0x1040, 0x1090, 0x17E0, 0x1810, 0x1946, 0x19D0, 0x1A80,
+    0x1A90, 0x1B50, 0x1BB0, 0x1C40, 0x1C50, 0xA620, 0xA8D0, 0xA900, 0xA9D0, 0xA9F0, 0xAA50, 0xABF0,
+    0xFF10, 0x104A0, 0x10D30, 0x11066, 0x110F0, 0x11136, 0x111D0, 0x112F0, 0x11450, 0x114D0,
+    0x11650, 0x116C0, 0x11730, 0x118E0, 0x11950, 0x11C50, 0x11D50, 0x11DA0, 0x11F50, 0x16A60,
+    0x16AC0, 0x16B50, 0x1D7CE, 0x1D7D8, 0x1D7E2, 0x1D7EC, 0x1D7F6, 0x1E140, 0x1E2F0, 0x1E4F0,
+    0x1E950, 0x1FBF0,
+];
+
+/// `char::to_digit(10)` for any Unicode decimal digit, not only ASCII ones:
+/// fullwidth `３`, Arabic-Indic `٣`, Devanagari `३`...
+pub fn unicode_digit(character: char) -> Option<u32> {
+    let code = character as u32;
+    let block = DECIMAL_ZEROS.partition_point(|&zero| zero <= code);
+    let zero = DECIMAL_ZEROS[block.checked_sub(1)?];
+
+    (code - zero < 10).then_some(code - zero)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Kind {
+    Digit,
+    Word,
+}
+
+impl fmt::Display for Kind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Kind::Digit => f.pad("digit"),
+            Kind::Word => f.pad("word"),
+        }
+    }
+}
+
+/// A word of the vocabulary found in a line, `start..end` being its byte
+/// span.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Match {
+    pub value: u32,
+    pub kind: Kind,
+    pub start: usize,
+    pub end: usize,
+}
+
+impl Match {
+    /// The leading digit of the value, what the match gives when it's the
+    /// first of a line: `twenty-three` gives 2.
+    pub fn first_digit(&self) -> u32 {
+        let mut value = self.value;
+        while value >= 10 {
+            value /= 10;
+        }
+        value
+    }
+
+    /// The trailing digit of the value, what the match gives when it's the
+    /// last of a line: `twenty-three` gives 3.
+    pub fn last_digit(&self) -> u32 {
+        self.value % 10
+    }
+}
+
+/// Aho-Corasick automaton over the bytes of a vocabulary of words, each
+/// worth a number. It finds every word of a line in a single pass, however
+/// many words overlap, instead of trying every word at every position.
+/// Words are valid UTF-8 so they can only match on character boundaries.
+#[derive(Debug)]
+pub struct Scanner {
+    /// `transitions[state][byte]` is the next state, failure links already
+    /// folded in so scanning never backtracks.
+    transitions: Vec<[usize; 256]>,
+    /// Words ending at every state, its own and those of its suffixes.
+    outputs: Vec<Vec<usize>>,
+    words: Vec<(String, u32, Kind)>,
+    unicode_digits: bool,
+}
+
+impl Scanner {
+    pub fn new<S: Into<String>>(words: impl IntoIterator<Item = (S, u32)>) -> Self {
+        let words = words
+            .into_iter()
+            .map(|(word, value)| {
+                let word: String = word.into();
+                // a single digit character is a digit, anything else a word
+                let mut characters = word.chars();
+                let kind = match (characters.next().and_then(unicode_digit), characters.next()) {
+                    (Some(_), None) => Kind::Digit,
+                    _ => Kind::Word,
+                };
+                (word, value, kind)
+            })
+            .filter(|(word, _, _)| !word.is_empty())
+            .collect::<Vec<(String, u32, Kind)>>();
+
+        // the trie, a missing transition is `0` as the root can't be a target
+        let mut transitions = vec![[0; 256]];
+        let mut outputs = vec![Vec::new()];
+        for (index, (word, _, _)) in words.iter().enumerate() {
+            let mut state = 0;
+            for &byte in word.as_bytes() {
+                if transitions[state][byte as usize] == 0 {
+                    transitions.push([0; 256]);
+                    outputs.push(Vec::new());
+                    transitions[state][byte as usize] = transitions.len() - 1;
+                }
+                state = transitions[state][byte as usize];
+            }
+            outputs[state].push(index);
+        }
+
+        // breadth first so the failure state of a node is always done before it
+        let mut failures = vec![0; transitions.len()];
+        let mut queue = transitions[0]
+            .iter()
+            .copied()
+            .filter(|&next| next != 0)
+            .collect::<VecDeque<usize>>();
+        while let Some(state) = queue.pop_front() {
+            let failure = failures[state];
+            let inherited = outputs[failure].clone();
+            outputs[state].extend(inherited);
+
+            let fallbacks = transitions[failure];
+            for (next, fallback) in transitions[state].iter_mut().zip(fallbacks) {
+                if *next == 0 {
+                    *next = fallback;
+                } else {
+                    failures[*next] = fallback;
+                    queue.push_back(*next);
+                }
+            }
+        }
+
+        Scanner {
+            transitions,
+            outputs,
+            words,
+            unicode_digits: false,
+        }
+    }
+
+    /// Also finds the non-ASCII decimal digits, see `unicode_digit`.
+    pub fn with_unicode_digits(mut self) -> Self {
+        self.unicode_digits = true;
+        self
+    }
+
+    /// Every word in `line`, ordered by where they end.
+    pub fn matches<'a>(&'a self, line: &'a str) -> impl Iterator<Item = Match> + 'a {
+        let mut state = 0;
+        line.char_indices().flat_map(move |(start, character)| {
+            let end = start + character.len_utf8();
+            for &byte in &line.as_bytes()[start..end] {
+                state = self.transitions[state][byte as usize];
+            }
+
+            let digit = Some(character)
+                .filter(|character| self.unicode_digits && !character.is_ascii())
+                .and_then(unicode_digit)
+                .map(|value| Match {
+                    value,
+                    kind: Kind::Digit,
+                    start,
+                    end,
+                });
+
+            self.outputs[state]
+                .iter()
+                .map(move |&word| {
+                    let (text, value, kind) = &self.words[word];
+                    Match {
+                        value: *value,
+                        kind: *kind,
+                        start: end - text.len(),
+                        end,
+                    }
+                })
+                .chain(digit)
+        })
+    }
+
+    /// The first and the last word of `line`: the one that starts first and
+    /// the one that ends last, the longest one when several do so that
+    /// `seventeen` is read as 17 and not 7.
+    pub fn first_and_last(&self, line: &str) -> Option<(Match, Match)> {
+        let mut matches = self.matches(line);
+        let found = matches.next()?;
+        let (mut first, mut last) = (found, found);
+        for found in matches {
+            if (found.start, Reverse(found.end)) < (first.start, Reverse(first.end)) {
+                first = found;
+            }
+            if (found.end, Reverse(found.start)) > (last.end, Reverse(last.start)) {
+                last = found;
+            }
+        }
+
+        Some((first, last))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scanner_finds_overlapping_words() {
+        let scanner = Scanner::new([("one", 1), ("eight", 8), ("two", 2), ("nine", 9)]);
+
+        let matches = scanner.matches("xtwoneightwo").collect::<Vec<Match>>();
+
+        let spans = matches
+            .iter()
+            .map(|found| (found.value, found.start, found.end))
+            .collect::<Vec<_>>();
+        assert_eq!(vec![(2, 1, 4), (1, 3, 6), (8, 5, 10), (2, 9, 12)], spans);
+
+        let (first, last) = scanner.first_and_last("xtwoneightwo").unwrap();
+        assert_eq!((2, 2), (first.value, last.value));
+        assert_eq!(None, scanner.first_and_last("xyz"));
+    }
+
+    #[test]
+    fn test_scanner_spans_stay_on_character_boundaries() {
+        let scanner = Scanner::new([("1", 1), ("two", 2), ("fünf", 5)]);
+
+        let spans = scanner
+            .matches("ñtwoü1fünf")
+            .map(|found| (found.value, found.start, found.end))
+            .collect::<Vec<_>>();
+        assert_eq!(vec![(2, 2, 5), (1, 7, 8), (5, 8, 13)], spans);
+
+        assert_eq!(None, scanner.first_and_last("３x٣"));
+
+        let scanner = scanner.with_unicode_digits();
+        let (first, last) = scanner.first_and_last("３x٣").unwrap();
+        assert_eq!((3, 0, 3), (first.value, first.start, first.end));
+        assert_eq!((3, 4, 6), (last.value, last.start, last.end));
+        assert_eq!(Some(7), unicode_digit('७'));
+        assert_eq!(None, unicode_digit('x'));
+        assert_eq!(None, unicode_digit('Ⅳ'));
+    }
+
+    #[test]
+    fn test_scanner_prefers_the_longest_of_nested_words() {
+        let scanner = Scanner::new([("nine", 9), ("nineteen", 19), ("teen", 10)]);
+
+        let (first, last) = scanner.first_and_last("nineteen").unwrap();
+
+        assert_eq!((19, 1), (first.value, first.first_digit()));
+        assert_eq!((19, 9), (last.value, last.last_digit()));
+    }
+}
@@ -0,0 +1,262 @@
+use std::{error::Error, fmt};
+
+use common::{split_with_offsets, Location};
+
+use crate::scanner::Scanner;
+
+const DIGITS: [(&str, u32); 10] = [
+    ("0", 0),
+    ("1", 1),
+    ("2", 2),
+    ("3", 3),
+    ("4", 4),
+    ("5", 5),
+    ("6", 6),
+    ("7", 7),
+    ("8", 8),
+    ("9", 9),
+];
+
+const ENGLISH: [(&str, u32); 9] = [
+    ("one", 1),
+    ("two", 2),
+    ("three", 3),
+    ("four", 4),
+    ("five", 5),
+    ("six", 6),
+    ("seven", 7),
+    ("eight", 8),
+    ("nine", 9),
+];
+
+const TEENS: [(&str, u32); 10] = [
+    ("ten", 10),
+    ("eleven", 11),
+    ("twelve", 12),
+    ("thirteen", 13),
+    ("fourteen", 14),
+    ("fifteen", 15),
+    ("sixteen", 16),
+    ("seventeen", 17),
+    ("eighteen", 18),
+    ("nineteen", 19),
+];
+
+const TENS: [(&str, u32); 8] = [
+    ("twenty", 20),
+    ("thirty", 30),
+    ("forty", 40),
+    ("fifty", 50),
+    ("sixty", 60),
+    ("seventy", 70),
+    ("eighty", 80),
+    ("ninety", 90),
+];
+
+const SPANISH: [(&str, u32); 9] = [
+    ("uno", 1),
+    ("dos", 2),
+    ("tres", 3),
+    ("cuatro", 4),
+    ("cinco", 5),
+    ("seis", 6),
+    ("siete", 7),
+    ("ocho", 8),
+    ("nueve", 9),
+];
+
+const GERMAN: [(&str, u32); 9] = [
+    ("eins", 1),
+    ("zwei", 2),
+    ("drei", 3),
+    ("vier", 4),
+    ("fünf", 5),
+    ("sechs", 6),
+    ("sieben", 7),
+    ("acht", 8),
+    ("neun", 9),
+];
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ParseError {
+    MissingNumber(Location),
+    InvalidNumber(Location),
+    Empty,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::MissingNumber(location) => {
+                write!(
+                    f,
+                    "{}: expected `<word> <number>`, got `{}`",
+                    location, location.text
+                )
+            }
+            ParseError::InvalidNumber(location) => {
+                write!(f, "{}: invalid number `{}`", location, location.text)
+            }
+            ParseError::Empty => write!(f, "the vocabulary has no words"),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+/// The words that count as numbers besides the digits themselves.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Vocabulary {
+    words: Vec<(String, u32)>,
+}
+
+impl Vocabulary {
+    fn from_table(table: &[(&str, u32)]) -> Self {
+        Vocabulary {
+            words: table
+                .iter()
+                .map(|&(word, digit)| (word.to_string(), digit))
+                .collect(),
+        }
+    }
+
+    /// No words at all, only the digits count.
+    pub fn digits_only() -> Self {
+        Vocabulary { words: Vec::new() }
+    }
+
+    pub fn english() -> Self {
+        Vocabulary::from_table(&ENGLISH)
+    }
+
+    /// English numbers from one to ninety-nine: the digit words, the teens,
+    /// the tens and their hyphenated compounds such as `twenty-three`.
+    pub fn english_numbers() -> Self {
+        let mut vocabulary = Vocabulary::from_table(&ENGLISH);
+        vocabulary.words.extend(
+            TEENS
+                .iter()
+                .chain(&TENS)
+                .map(|&(word, value)| (word.to_string(), value)),
+        );
+        for (tens, tens_value) in TENS {
+            for (unit, unit_value) in ENGLISH {
+                let compound = format!("{}-{}", tens, unit);
+                vocabulary.words.push((compound, tens_value + unit_value));
+            }
+        }
+        vocabulary
+    }
+
+    /// One of the built-in vocabularies: `english`, `english-numbers`,
+    /// `spanish` or `german`.
+    pub fn builtin(name: &str) -> Option<Self> {
+        match name {
+            "english" => Some(Vocabulary::from_table(&ENGLISH)),
+            "english-numbers" => Some(Vocabulary::english_numbers()),
+            "spanish" => Some(Vocabulary::from_table(&SPANISH)),
+            "german" => Some(Vocabulary::from_table(&GERMAN)),
+            _ => None,
+        }
+    }
+
+    /// Reads a vocabulary with a `<word> <number>` pair per line. Blank lines
+    /// and lines starting with `#` are skipped.
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut words = Vec::new();
+        for (index, line) in input.lines().enumerate() {
+            let line_number = index + 1;
+            if line.trim().is_empty() || line.trim_start().starts_with('#') {
+                continue;
+            }
+
+            let content = split_with_offsets(line, ' ')
+                .filter(|&(_, el)| !el.is_empty())
+                .collect::<Vec<(usize, &str)>>();
+            let [(_, word), (number_offset, number)] = content[..] else {
+                return Err(ParseError::MissingNumber(Location::new(
+                    line_number,
+                    1,
+                    line,
+                )));
+            };
+
+            let value = number.parse::<u32>().map_err(|_| {
+                ParseError::InvalidNumber(Location::new(line_number, number_offset + 1, number))
+            })?;
+            words.push((word.to_string(), value));
+        }
+
+        if words.is_empty() {
+            return Err(ParseError::Empty);
+        }
+
+        Ok(Vocabulary { words })
+    }
+
+    pub fn value(&self, word: &str) -> Option<u32> {
+        self.words
+            .iter()
+            .find(|(known, _)| known == word)
+            .map(|&(_, value)| value)
+    }
+
+    /// A scanner for these words and the digits. It needs no window sized
+    /// after the longest word, however long words get.
+    pub fn scanner(&self) -> Scanner {
+        Scanner::new(
+            DIGITS
+                .iter()
+                .map(|&(digit, value)| (digit.to_string(), value))
+                .chain(self.words.iter().cloned()),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_vocabulary() {
+        let vocabulary =
+            Vocabulary::parse("# french\nun 1\ndeux 2\n\ntrois   3\ndouze 12").unwrap();
+
+        assert_eq!(Some(3), vocabulary.value("trois"));
+        assert_eq!(Some(12), vocabulary.value("douze"));
+
+        let error = ParseError::InvalidNumber(Location::new(2, 6, "-2"));
+        assert_eq!(Err(error), Vocabulary::parse("un 1\ndeux -2"));
+        let error = ParseError::MissingNumber(Location::new(1, 1, "un"));
+        assert_eq!(Err(error), Vocabulary::parse("un"));
+        assert_eq!(Err(ParseError::Empty), Vocabulary::parse("# nothing"));
+    }
+
+    #[test]
+    fn test_builtin_vocabularies_find_their_words() {
+        let scanner = Vocabulary::builtin("spanish").unwrap().scanner();
+        let (first, last) = scanner.first_and_last("xdosieteuno").unwrap();
+        assert_eq!((2, 1), (first.value, last.value));
+
+        let scanner = Vocabulary::builtin("german").unwrap().scanner();
+        let (first, last) = scanner.first_and_last("fünfzig4acht").unwrap();
+        assert_eq!((5, 8), (first.value, last.value));
+
+        assert_eq!(None, Vocabulary::builtin("klingon"));
+    }
+
+    #[test]
+    fn test_english_numbers_read_compounds_whole() {
+        let vocabulary = Vocabulary::builtin("english-numbers").unwrap();
+        assert_eq!(Some(99), vocabulary.value("ninety-nine"));
+        assert_eq!(Some(12), vocabulary.value("twelve"));
+
+        let scanner = vocabulary.scanner();
+        let (first, last) = scanner.first_and_last("seventeenxtwenty-three").unwrap();
+        assert_eq!((17, 23), (first.value, last.value));
+        assert_eq!((1, 3), (first.first_digit(), last.last_digit()));
+
+        let (first, last) = scanner.first_and_last("twelve4ten").unwrap();
+        assert_eq!((1, 0), (first.first_digit(), last.last_digit()));
+    }
+}
@@ -0,0 +1,10 @@
+use day1::{find_first_digit, find_last_digit, map_word_to_digit};
+
+#[test]
+fn test_words_sharing_letters_count_as_both_digits() {
+    assert_eq!(Some(8), map_word_to_digit("eight"));
+    assert_eq!(None, map_word_to_digit("eigh"));
+
+    assert_eq!(8, find_first_digit("eightwo"));
+    assert_eq!(2, find_last_digit("eightwo"));
+}
@@ -0,0 +1,430 @@
+use std::{collections::BTreeMap, error::Error, fmt};
+
+use crate::{minimum_bag, Game};
+
+/// Half the 95% quantile of the chi-squared distribution with one degree of
+/// freedom, how far the log-likelihood may drop inside a 95% interval.
+const HALF_CHI2_95: f64 = 1.920729;
+
+/// The largest bag the counts are estimated for, past it the gains of one
+/// more cube are too close to one another for a `f64` to rank them.
+pub const MAX_BAG_SIZE: u64 = 1 << 40;
+
+/// The estimated share of the bag one color takes up, when its size isn't
+/// known.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Share {
+    pub color: String,
+    /// Cubes of this color seen over all the draws.
+    pub seen: u64,
+    /// The most likely share of the bag.
+    pub share: f64,
+    /// Bounds of the 95% interval of the share.
+    pub low: f64,
+    pub high: f64,
+}
+
+/// The estimated number of cubes of one color in the bag.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Estimate {
+    pub color: String,
+    /// Cubes of this color seen over all the draws.
+    pub seen: u64,
+    /// The fewest cubes of this color the games need.
+    pub minimum: u64,
+    /// The most likely number of cubes of this color.
+    pub cubes: u64,
+    /// Bounds of the 95% interval of the number of cubes.
+    pub low: u64,
+    pub high: u64,
+}
+
+impl Estimate {
+    /// The share of a bag of `size` cubes this color takes up.
+    pub fn share(&self, size: u64) -> f64 {
+        self.cubes as f64 / size as f64
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum EstimateError {
+    BagTooSmall { size: u64, minimum: u64 },
+    BagTooLarge { size: u64 },
+}
+
+impl fmt::Display for EstimateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EstimateError::BagTooSmall { size, minimum } => write!(
+                f,
+                "a bag of {} cubes can't hold the {} the games need",
+                size, minimum
+            ),
+            EstimateError::BagTooLarge { size } => write!(
+                f,
+                "can't estimate a bag of {} cubes, the largest is {}",
+                size, MAX_BAG_SIZE
+            ),
+        }
+    }
+}
+
+impl Error for EstimateError {}
+
+/// What is known of one color: how often it was seen and how many cubes of
+/// it the bag has at least.
+#[derive(Debug, Clone, Copy)]
+struct Color {
+    seen: u64,
+    minimum: u64,
+}
+
+impl Color {
+    /// How much the log-likelihood grows when the bag has `cubes + 1` cubes
+    /// of this color instead of `cubes`.
+    fn gain(&self, cubes: u64) -> f64 {
+        if self.seen == 0 {
+            return 0.0;
+        }
+        self.seen as f64 * (1.0 / cubes as f64).ln_1p()
+    }
+
+    /// The cubes of this color once every addition gaining at least
+    /// `threshold` is made.
+    fn cubes_above(&self, threshold: f64) -> u64 {
+        if self.seen == 0 {
+            return self.minimum;
+        }
+        // gain(n) >= threshold for every n up to 1 / (e^(threshold / seen) - 1)
+        let last = (1.0 / (threshold / self.seen as f64).exp_m1()).floor();
+        self.minimum.max((last as u64).saturating_add(1))
+    }
+}
+
+fn log_likelihood(colors: &[Color], cubes: &[u64]) -> f64 {
+    colors
+        .iter()
+        .zip(cubes)
+        .filter(|(color, _)| color.seen > 0)
+        .map(|(color, &cubes)| color.seen as f64 * (cubes as f64).ln())
+        .sum()
+}
+
+/// The most likely counts of `colors` in a bag of `size` cubes, none below
+/// its minimum, or `None` when the minimums don't fit. Starting from the
+/// minimums, every cube left goes to the color it makes the draws the most
+/// likely with. That's a threshold on the gain, which is searched for
+/// instead of adding the cubes one at a time, only the last few are.
+fn allocate(colors: &[Color], size: u64) -> Option<Vec<u64>> {
+    let floor = colors.iter().map(|color| color.minimum).sum::<u64>();
+    if floor > size || (colors.is_empty() && size > 0) {
+        return None;
+    }
+
+    let total = |cubes: &[u64]| {
+        cubes
+            .iter()
+            .fold(0u64, |total, &cubes| total.saturating_add(cubes))
+    };
+    let at = |threshold: f64| {
+        colors
+            .iter()
+            .map(|color| color.cubes_above(threshold))
+            .collect::<Vec<u64>>()
+    };
+
+    let mut cubes = colors
+        .iter()
+        .map(|color| color.minimum)
+        .collect::<Vec<u64>>();
+    if size > floor && colors.iter().any(|color| color.seen > 0) {
+        // nothing gains more than twice the most any first addition does
+        let (mut low, mut high) = (
+            0.0,
+            2.0 * colors
+                .iter()
+                .map(|color| color.gain(color.minimum))
+                .fold(0.0, f64::max),
+        );
+        for _ in 0..100 {
+            let middle = (low + high) / 2.0;
+            if total(&at(middle)) <= size {
+                high = middle;
+            } else {
+                low = middle;
+            }
+        }
+        cubes = at(high);
+    }
+
+    let mut left = size - total(&cubes);
+    while left > 0 {
+        let (best, gain) = colors
+            .iter()
+            .zip(&cubes)
+            .map(|(color, &cubes)| color.gain(cubes))
+            .enumerate()
+            .fold((0, f64::MIN), |best, (index, gain)| {
+                if gain > best.1 {
+                    (index, gain)
+                } else {
+                    best
+                }
+            });
+        // colors never seen don't change the likelihood, any of them does
+        let added = if gain > 0.0 { 1 } else { left };
+        cubes[best] += added;
+        left -= added;
+    }
+
+    Some(cubes)
+}
+
+/// The highest log-likelihood of the draws with `cubes` cubes of
+/// `colors[index]` in a bag of `size`, the other colors taking the most
+/// likely counts of what's left.
+fn profile(colors: &[Color], index: usize, cubes: u64, size: u64) -> Option<f64> {
+    let mut others = colors.to_vec();
+    let color = others.remove(index);
+    let rest = allocate(&others, size.checked_sub(cubes)?)?;
+
+    Some(log_likelihood(&[color], &[cubes]) + log_likelihood(&others, &rest))
+}
+
+/// Cubes of every color seen over all the draws.
+fn seen_cubes(games: &[Game]) -> BTreeMap<&str, u64> {
+    let mut seen = BTreeMap::<&str, u64>::new();
+    for draw in games.iter().flat_map(|game| &game.draws) {
+        for (color, count) in draw.cubes.iter() {
+            *seen.entry(color).or_insert(0) += u64::from(count);
+        }
+    }
+
+    seen
+}
+
+/// Estimates the share of the bag every color takes up, taking every cube
+/// of every draw as picked independently, that is with replacement. The
+/// most likely share is how often the color was seen out of all the cubes
+/// seen, its interval the shares whose likelihood is within the 95% limit
+/// of it. Without a size, the minimums don't bound the shares: a bag large
+/// enough holds them at any share.
+pub fn estimate_shares(games: &[Game]) -> Vec<Share> {
+    let seen = seen_cubes(games);
+    let total = seen.values().sum::<u64>();
+    if total == 0 {
+        return Vec::new();
+    }
+
+    let n = total as f64;
+    seen.into_iter()
+        .map(|(color, seen)| {
+            let s = seen as f64;
+            let likelihood = |p: f64| {
+                let hit = if seen > 0 { s * p.ln() } else { 0.0 };
+                let miss = if seen < total {
+                    (n - s) * (-p).ln_1p()
+                } else {
+                    0.0
+                };
+                hit + miss
+            };
+            let share = s / n;
+            let limit = likelihood(share) - HALF_CHI2_95;
+            // where the likelihood crosses the limit between `inside` and
+            // `outside`, it only falls moving away from the share
+            let bound = |mut inside: f64, mut outside: f64| {
+                for _ in 0..100 {
+                    let middle = (inside + outside) / 2.0;
+                    if likelihood(middle) >= limit {
+                        inside = middle;
+                    } else {
+                        outside = middle;
+                    }
+                }
+                inside
+            };
+
+            Share {
+                color: color.to_string(),
+                seen,
+                share,
+                low: if seen == 0 { 0.0 } else { bound(share, 0.0) },
+                high: if seen == total {
+                    1.0
+                } else {
+                    bound(share, 1.0)
+                },
+            }
+        })
+        .collect::<Vec<Share>>()
+}
+
+/// Estimates the makeup of a bag of `size` cubes, taking every cube of
+/// every draw as picked independently, that is with replacement. The most
+/// likely counts are whole numbers, at least what the games need of each
+/// color and adding up to `size`. The interval of a color holds the counts
+/// whose likelihood, with the other colors at their most likely for it,
+/// is within the 95% limit of the best one.
+pub fn estimate_bag(games: &[Game], size: u64) -> Result<Vec<Estimate>, EstimateError> {
+    let minimum_bag = minimum_bag(games);
+    if minimum_bag.size() > size {
+        return Err(EstimateError::BagTooSmall {
+            size,
+            minimum: minimum_bag.size(),
+        });
+    }
+    if size > MAX_BAG_SIZE {
+        return Err(EstimateError::BagTooLarge { size });
+    }
+
+    let seen = seen_cubes(games);
+
+    let names = minimum_bag
+        .iter()
+        .map(|(color, _)| color)
+        .collect::<Vec<&str>>();
+    let colors = minimum_bag
+        .iter()
+        .map(|(color, minimum)| Color {
+            seen: seen.get(color).copied().unwrap_or(0),
+            minimum: u64::from(minimum),
+        })
+        .collect::<Vec<Color>>();
+    let Some(cubes) = allocate(&colors, size) else {
+        return Ok(Vec::new());
+    };
+    let limit = log_likelihood(&colors, &cubes) - HALF_CHI2_95;
+
+    Ok(colors
+        .iter()
+        .enumerate()
+        .map(|(index, color)| {
+            let within = |count: u64| {
+                profile(&colors, index, count, size).is_some_and(|value| value >= limit)
+            };
+            // the profile is concave, the counts within the limit are a range
+            let (mut low, mut high) = (color.minimum, cubes[index]);
+            while low < high {
+                let middle = low + (high - low) / 2;
+                if within(middle) {
+                    high = middle;
+                } else {
+                    low = middle + 1;
+                }
+            }
+            let lowest = low;
+            let others = colors.iter().map(|color| color.minimum).sum::<u64>() - color.minimum;
+            let (mut low, mut high) = (cubes[index], size - others);
+            while low < high {
+                let middle = high - (high - low) / 2;
+                if within(middle) {
+                    low = middle;
+                } else {
+                    high = middle - 1;
+                }
+            }
+
+            Estimate {
+                color: names[index].to_string(),
+                seen: color.seen,
+                minimum: color.minimum,
+                cubes: cubes[index],
+                low: lowest,
+                high,
+            }
+        })
+        .collect::<Vec<Estimate>>())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_games;
+
+    #[test]
+    fn test_estimate_bag_gives_counts_and_intervals() {
+        let games = parse_games("Game 1: 3 red, 1 blue; 2 red, 2 blue").unwrap();
+
+        let estimates = estimate_bag(&games, 16).unwrap();
+
+        assert_eq!(2, estimates.len());
+        let (blue, red) = (&estimates[0], &estimates[1]);
+        assert_eq!(("red", 5, 3), (red.color.as_str(), red.seen, red.minimum));
+        assert_eq!((6, 10), (blue.cubes, red.cubes));
+        assert_eq!(0.625, red.share(16));
+        assert!(red.low < red.cubes && red.cubes < red.high && red.high <= 14);
+        assert_eq!((16 - red.high, 16 - red.low), (blue.low, blue.high));
+
+        let error = EstimateError::BagTooSmall {
+            size: 4,
+            minimum: 5,
+        };
+        assert_eq!(Err(error), estimate_bag(&games, 4));
+    }
+
+    #[test]
+    fn test_estimate_bag_keeps_the_minimums() {
+        // blue's 3 out of 123 cubes seen would be 2.4 cubes of 100
+        let games = parse_games("Game 1: 3 blue; 30 red; 30 red; 30 red; 30 red").unwrap();
+
+        let estimates = estimate_bag(&games, 100).unwrap();
+        let (blue, red) = (&estimates[0], &estimates[1]);
+        assert_eq!((3, 97), (blue.cubes, red.cubes));
+        assert_eq!(3, blue.low);
+        assert_eq!(97, red.high);
+
+        let estimates = estimate_bag(&games, 33).unwrap();
+        assert_eq!(
+            (3, 3, 3),
+            (estimates[0].low, estimates[0].cubes, estimates[0].high)
+        );
+        assert_eq!((30, 30), (estimates[1].low, estimates[1].high));
+    }
+
+    #[test]
+    fn test_estimate_bag_narrows_with_more_draws() {
+        let few = parse_games("Game 1: 1 red, 1 blue").unwrap();
+        let many = parse_games(&"Game 1: 1 red, 1 blue\n".repeat(100)).unwrap();
+
+        let few = &estimate_bag(&few, 1_000_000).unwrap()[0];
+        let many = &estimate_bag(&many, 1_000_000).unwrap()[0];
+
+        assert_eq!((500_000, 500_000), (few.cubes, many.cubes));
+        assert!(many.high - many.low < few.high - few.low);
+        assert!(estimate_bag(&[], 10).unwrap().is_empty());
+
+        let games = parse_games("Game 1: 1 red, 1 blue").unwrap();
+        let size = u64::MAX;
+        assert_eq!(
+            Err(EstimateError::BagTooLarge { size }),
+            estimate_bag(&games, size)
+        );
+        let estimates = estimate_bag(&games, MAX_BAG_SIZE).unwrap();
+        assert_eq!(MAX_BAG_SIZE / 2, estimates[0].cubes);
+        assert!(estimates[0].low <= estimates[0].cubes && estimates[0].cubes <= estimates[0].high);
+    }
+
+    #[test]
+    fn test_estimate_shares_without_a_size() {
+        let games = parse_games("Game 1: 3 red, 1 blue; 2 red, 2 blue; 1 green").unwrap();
+
+        let shares = estimate_shares(&games);
+
+        assert_eq!(3, shares.len());
+        let red = &shares[2];
+        assert_eq!(
+            ("red", 5, 5.0 / 9.0),
+            (red.color.as_str(), red.seen, red.share)
+        );
+        assert!(0.2 < red.low && red.low < red.share);
+        assert!(red.share < red.high && red.high < 0.9);
+
+        let games = parse_games("Game 1: 4 red; 0 blue").unwrap();
+        let shares = estimate_shares(&games);
+        assert_eq!((0.0, 0.0), (shares[0].share, shares[0].low));
+        assert!(shares[0].high > 0.0);
+        assert_eq!((1.0, 1.0), (shares[1].share, shares[1].high));
+        assert!(estimate_shares(&[]).is_empty());
+    }
+}
@@ -0,0 +1,183 @@
+use std::{error::Error, fmt, ops::RangeInclusive};
+
+use crate::{CubeSet, Draw, Game};
+
+/// Xorshift, enough to pick cubes and reproducible from a seed.
+#[derive(Debug, Clone)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        // xorshift never leaves 0
+        Rng(if seed == 0 { 0x2023_1202 } else { seed })
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    pub fn between(&mut self, range: &RangeInclusive<u32>) -> u32 {
+        let span = u64::from(range.end() - range.start()) + 1;
+        range.start() + (self.next_u64() % span) as u32
+    }
+}
+
+/// How many games to make and how big they are.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Settings {
+    pub games: u32,
+    pub draws: RangeInclusive<u32>,
+    pub cubes: RangeInclusive<u32>,
+    pub seed: u64,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum GenerateError {
+    NoDraws,
+    EmptyDraw,
+    BagTooSmall { cubes: u32, size: u64 },
+}
+
+impl fmt::Display for GenerateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GenerateError::NoDraws => write!(f, "games need at least one draw"),
+            GenerateError::EmptyDraw => write!(f, "draws need at least one cube"),
+            GenerateError::BagTooSmall { cubes, size } => {
+                write!(f, "can't draw {} cubes out of a bag of {}", cubes, size)
+            }
+        }
+    }
+}
+
+impl Error for GenerateError {}
+
+/// Reads `<n>` or `<min>-<max>`.
+pub fn parse_range(range: &str) -> Option<RangeInclusive<u32>> {
+    let (min, max) = range.split_once('-').unwrap_or((range, range));
+    let (min, max) = (min.parse::<u32>().ok()?, max.parse::<u32>().ok()?);
+    (min <= max).then_some(min..=max)
+}
+
+/// Plays `settings.games` games with `bag`: every draw takes a handful of
+/// cubes out of it at random, shows them and puts them back. Every game is
+/// then possible with `bag`, which makes the answers known in advance.
+pub fn generate_games(bag: &CubeSet, settings: &Settings) -> Result<Vec<Game>, GenerateError> {
+    if *settings.draws.start() == 0 {
+        return Err(GenerateError::NoDraws);
+    }
+    if *settings.cubes.start() == 0 {
+        return Err(GenerateError::EmptyDraw);
+    }
+    if u64::from(*settings.cubes.end()) > bag.size() {
+        return Err(GenerateError::BagTooSmall {
+            cubes: *settings.cubes.end(),
+            size: bag.size(),
+        });
+    }
+
+    let mut rng = Rng::new(settings.seed);
+    let mut games = Vec::with_capacity(settings.games as usize);
+    for id in 1..=settings.games {
+        let draws = (0..rng.between(&settings.draws))
+            .map(|_| {
+                // every cube left is as likely to come out, so each color is
+                // picked in proportion to how many of it are still in the bag
+                let mut left = bag
+                    .iter()
+                    .map(|(color, count)| (color, u64::from(count)))
+                    .collect::<Vec<(&str, u64)>>();
+                let mut size = bag.size();
+                let mut drawn = CubeSet::default();
+                for _ in 0..rng.between(&settings.cubes) {
+                    let mut picked = rng.next_u64() % size;
+                    for (color, count) in left.iter_mut() {
+                        if picked < *count {
+                            *count -= 1;
+                            drawn.add(color, 1);
+                            break;
+                        }
+                        picked -= *count;
+                    }
+                    size -= 1;
+                }
+
+                Draw { cubes: drawn }
+            })
+            .collect::<Vec<Draw>>();
+        games.push(Game { id, draws });
+    }
+
+    Ok(games)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{find_possible_games, parse_games};
+
+    fn settings(seed: u64) -> Settings {
+        Settings {
+            games: 50,
+            draws: 1..=6,
+            cubes: 1..=20,
+            seed,
+        }
+    }
+
+    #[test]
+    fn test_generated_games_parse_back_and_fit_the_bag() {
+        let bag = CubeSet::parse_bag("red=12,green=13,blue=14,yellow=1").unwrap();
+
+        let games = generate_games(&bag, &settings(7)).unwrap();
+        let input = games
+            .iter()
+            .map(|game| format!("{}\n", game))
+            .collect::<String>();
+        let parsed = parse_games(&input).unwrap();
+
+        assert_eq!(games, parsed);
+        assert_eq!(
+            50 * 51 / 2,
+            find_possible_games(&parsed, &bag).iter().sum::<u32>()
+        );
+    }
+
+    #[test]
+    fn test_generate_games_is_reproducible() {
+        let bag = CubeSet::puzzle_bag();
+
+        let games = generate_games(&bag, &settings(1)).unwrap();
+
+        assert_eq!(games, generate_games(&bag, &settings(1)).unwrap());
+        assert_ne!(games, generate_games(&bag, &settings(2)).unwrap());
+
+        let mut too_many = settings(1);
+        too_many.cubes = 1..=40;
+        let error = GenerateError::BagTooSmall {
+            cubes: 40,
+            size: 39,
+        };
+        assert_eq!(Err(error), generate_games(&bag, &too_many));
+        assert_eq!(Some(2..=5), parse_range("2-5"));
+        assert_eq!(Some(3..=3), parse_range("3"));
+        assert_eq!(None, parse_range("5-2"));
+    }
+
+    #[test]
+    fn test_generate_games_with_a_huge_bag() {
+        let bag = CubeSet::parse_bag("red=4294967295,blue=2").unwrap();
+        assert_eq!(4_294_967_297, bag.size());
+
+        let games = generate_games(&bag, &settings(3)).unwrap();
+
+        assert_eq!(50, games.len());
+        assert_eq!(
+            50 * 51 / 2,
+            find_possible_games(&games, &bag).iter().sum::<u32>()
+        );
+    }
+}
@@ -0,0 +1,640 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    error, fmt,
+};
+
+use common::{split_with_offsets, Answer, Detail, Location, Part, Solution};
+
+pub mod estimate;
+pub mod generate;
+
+/// Any single word of letters names a color, there is no fixed set of them.
+pub fn is_color(name: &str) -> bool {
+    !name.is_empty() && name.chars().all(char::is_alphabetic)
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Error {
+    MissingColon(Location),
+    InvalidGameId(Location),
+    MissingDraws(Location),
+    MissingCount(Location),
+    MissingColor(Location),
+    InvalidColor(Location),
+    InvalidBagEntry(Location),
+    TooManyCubes(Location),
+    EmptyBag,
+    PowerTooLarge(u32),
+    TotalPowerTooLarge,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::MissingColon(location) => {
+                write!(
+                    f,
+                    "{}: expected `Game <id>: ...`, got `{}`",
+                    location, location.text
+                )
+            }
+            Error::InvalidGameId(location) => {
+                write!(f, "{}: invalid game id `{}`", location, location.text)
+            }
+            Error::MissingDraws(location) => {
+                write!(f, "{}: game `{}` has no draws", location, location.text)
+            }
+            Error::MissingCount(location) => {
+                write!(f, "{}: missing cube count in `{}`", location, location.text)
+            }
+            Error::MissingColor(location) => {
+                write!(f, "{}: missing cube color in `{}`", location, location.text)
+            }
+            Error::InvalidColor(location) => {
+                write!(f, "{}: invalid color `{}`", location, location.text)
+            }
+            Error::InvalidBagEntry(location) => {
+                write!(
+                    f,
+                    "{}: expected `<color>=<count>`, got `{}`",
+                    location, location.text
+                )
+            }
+            Error::TooManyCubes(location) => write!(
+                f,
+                "{}: more `{}` cubes than fit in 32 bits",
+                location, location.text
+            ),
+            Error::EmptyBag => write!(f, "the bag has no cubes"),
+            Error::PowerTooLarge(id) => {
+                write!(f, "the power of game {} doesn't fit in 64 bits", id)
+            }
+            Error::TotalPowerTooLarge => {
+                write!(f, "the sum of the powers doesn't fit in 64 bits")
+            }
+        }
+    }
+}
+
+impl error::Error for Error {}
+
+/// How many cubes of each color, those shown in a draw or those a game
+/// needs at least.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct CubeSet {
+    counts: BTreeMap<String, u32>,
+}
+
+impl CubeSet {
+    /// The bag of the puzzle: 12 red, 13 green and 14 blue cubes.
+    pub fn puzzle_bag() -> Self {
+        let mut bag = CubeSet::default();
+        bag.add("red", 12);
+        bag.add("green", 13);
+        bag.add("blue", 14);
+        bag
+    }
+
+    /// Reads a bag from `<color>=<count>` entries separated by commas or
+    /// lines, such as `red=20,green=5,blue=9`. Blank lines and lines
+    /// starting with `#` are skipped.
+    pub fn parse_bag(input: &str) -> Result<Self, Error> {
+        let mut bag = CubeSet::default();
+        for (index, line) in input.lines().enumerate() {
+            let line_number = index + 1;
+            if line.trim_start().starts_with('#') {
+                continue;
+            }
+
+            for (offset, entry) in split_with_offsets(line, ',') {
+                let column = offset + (entry.len() - entry.trim_start().len()) + 1;
+                let entry = entry.trim();
+                if entry.is_empty() {
+                    continue;
+                }
+
+                let invalid_entry =
+                    || Error::InvalidBagEntry(Location::new(line_number, column, entry));
+                let (color, count) = entry.split_once('=').ok_or_else(invalid_entry)?;
+                let count = count.trim().parse::<u32>().map_err(|_| invalid_entry())?;
+                let color = color.trim();
+                if !is_color(color) {
+                    let location = Location::new(line_number, column, color);
+                    return Err(Error::InvalidColor(location));
+                }
+                bag.counts.insert(color.to_string(), count);
+            }
+        }
+
+        if bag.counts.is_empty() {
+            return Err(Error::EmptyBag);
+        }
+
+        Ok(bag)
+    }
+
+    pub fn count(&self, color: &str) -> u32 {
+        self.counts.get(color).copied().unwrap_or(0)
+    }
+
+    /// Adds `count` cubes of `color`, a color showing up twice in a draw
+    /// counts both times. Gives the new count, or `None` and leaves the set
+    /// as it was when that doesn't fit in a `u32`.
+    pub fn add(&mut self, color: &str, count: u32) -> Option<u32> {
+        let current = self.counts.entry(color.to_string()).or_insert(0);
+        *current = current.checked_add(count)?;
+        Some(*current)
+    }
+
+    /// Raises every count to at least the one of `other`.
+    fn raise_to(&mut self, other: &CubeSet) {
+        for (color, count) in other.iter() {
+            let current = self.counts.entry(color.to_string()).or_insert(0);
+            *current = (*current).max(count);
+        }
+    }
+
+    /// The total number of cubes, wide enough for any number of colors.
+    pub fn size(&self) -> u64 {
+        self.counts.values().map(|&count| u64::from(count)).sum()
+    }
+
+    /// The colors and their counts, in alphabetical order.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, u32)> {
+        self.counts
+            .iter()
+            .map(|(color, &count)| (color.as_str(), count))
+    }
+
+    /// The product of the counts of the colors in the set, `None` when it
+    /// doesn't fit in 64 bits, which a few colors of large counts are enough
+    /// for.
+    pub fn power(&self) -> Option<u64> {
+        self.counts
+            .values()
+            .try_fold(1u64, |power, &count| power.checked_mul(u64::from(count)))
+    }
+
+    /// Whether every count fits in `bag`, which has none of the colors it
+    /// doesn't mention.
+    pub fn is_possible(&self, bag: &CubeSet) -> bool {
+        self.iter().all(|(color, count)| count <= bag.count(color))
+    }
+}
+
+/// The cubes shown at once during a game.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Draw {
+    pub cubes: CubeSet,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Game {
+    pub id: u32,
+    pub draws: Vec<Draw>,
+}
+
+impl Game {
+    pub fn parse_game(line: &str, line_number: usize) -> Result<Self, Error> {
+        let (prefix, draws) = line
+            .split_once(':')
+            .ok_or_else(|| Error::MissingColon(Location::new(line_number, 1, line)))?;
+
+        let id = prefix
+            .strip_prefix("Game")
+            .and_then(|id| id.trim().parse::<u32>().ok())
+            .ok_or_else(|| Error::InvalidGameId(Location::new(line_number, 1, prefix)))?;
+
+        if draws.trim().is_empty() {
+            return Err(Error::MissingDraws(Location::new(
+                line_number,
+                prefix.len() + 1,
+                prefix,
+            )));
+        }
+
+        let draws_offset = prefix.len() + 1;
+        let draws = split_with_offsets(draws, ';')
+            .map(|(offset, draw)| parse_draw(draw, (line_number, draws_offset + offset)))
+            .collect::<Result<Vec<Draw>, Error>>()?;
+
+        Ok(Game { id, draws })
+    }
+
+    /// The fewest cubes of each color the bag could have held: the most of
+    /// it shown in any single draw.
+    pub fn minimum_set(&self) -> CubeSet {
+        let mut minimum_set = CubeSet::default();
+        for draw in &self.draws {
+            minimum_set.raise_to(&draw.cubes);
+        }
+
+        minimum_set
+    }
+
+    pub fn is_possible(&self, bag: &CubeSet) -> bool {
+        self.draws.iter().all(|draw| draw.cubes.is_possible(bag))
+    }
+
+    /// Every color of every draw that needs more cubes than `bag` has.
+    pub fn violations(&self, bag: &CubeSet) -> Vec<Violation> {
+        let mut violations = vec![];
+        for (index, draw) in self.draws.iter().enumerate() {
+            for (color, count) in draw.cubes.iter() {
+                let limit = bag.count(color);
+                if count > limit {
+                    violations.push(Violation {
+                        draw: index + 1,
+                        color: color.to_string(),
+                        count,
+                        limit,
+                    });
+                }
+            }
+        }
+
+        violations
+    }
+}
+
+impl fmt::Display for Game {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Game {}:", self.id)?;
+        for (index, draw) in self.draws.iter().enumerate() {
+            let separator = if index == 0 { " " } else { "; " };
+            write!(f, "{}", separator)?;
+            for (index, (color, count)) in draw.cubes.iter().enumerate() {
+                let separator = if index == 0 { "" } else { ", " };
+                write!(f, "{}{} {}", separator, count, color)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// A color shown more times in a draw than there are cubes of it in the bag.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Violation {
+    /// Number of the draw in its game, starting at 1.
+    pub draw: usize,
+    pub color: String,
+    pub count: u32,
+    pub limit: u32,
+}
+
+impl Violation {
+    pub fn excess(&self) -> u32 {
+        self.count - self.limit
+    }
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "draw {}: {} {}, {} more than the {} in the bag",
+            self.draw,
+            self.count,
+            self.color,
+            self.excess(),
+            self.limit
+        )
+    }
+}
+
+/// Why a game isn't possible with a bag.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Rejection {
+    pub id: u32,
+    pub line: usize,
+    pub violations: Vec<Violation>,
+}
+
+impl fmt::Display for Rejection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "game {} on line {} is impossible", self.id, self.line)?;
+        for violation in &self.violations {
+            write!(f, "\n  {}", violation)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Parses the `<count> <color>, ...` of a draw starting at byte `offset`
+/// of its line.
+fn parse_draw(draw: &str, location: (usize, usize)) -> Result<Draw, Error> {
+    let (line_number, offset) = location;
+    let mut cubes = CubeSet::default();
+    for (cube_offset, cube) in split_with_offsets(draw, ',') {
+        let content = split_with_offsets(cube, ' ')
+            .filter(|&(_, el)| !el.is_empty())
+            .collect::<Vec<(usize, &str)>>();
+        let Some(&(count_offset, count)) = content.first() else {
+            continue;
+        };
+
+        let column = offset + cube_offset + count_offset + 1;
+        let count = count
+            .parse::<u32>()
+            .map_err(|_| Error::MissingCount(Location::new(line_number, column, draw.trim())))?;
+        let Some(&(color_offset, _)) = content.get(1) else {
+            return Err(Error::MissingColor(Location::new(
+                line_number,
+                column,
+                draw.trim(),
+            )));
+        };
+
+        let column = offset + cube_offset + color_offset + 1;
+        let color = cube[color_offset..].trim_end();
+        if !is_color(color) {
+            return Err(Error::InvalidColor(Location::new(
+                line_number,
+                column,
+                color,
+            )));
+        }
+        cubes
+            .add(color, count)
+            .ok_or_else(|| Error::TooManyCubes(Location::new(line_number, column, color)))?;
+    }
+
+    Ok(Draw { cubes })
+}
+
+pub fn parse_games(input: &str) -> Result<Vec<Game>, Error> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| Game::parse_game(line, index + 1))
+        .collect::<Result<Vec<Game>, Error>>()
+}
+
+/// Every color the games show, the color set of an input.
+pub fn colors(games: &[Game]) -> BTreeSet<&str> {
+    games
+        .iter()
+        .flat_map(|game| &game.draws)
+        .flat_map(|draw| draw.cubes.iter().map(|(color, _)| color))
+        .collect::<BTreeSet<&str>>()
+}
+
+/// The ids of the games that were possible with `bag`.
+pub fn find_possible_games(games: &[Game], bag: &CubeSet) -> Vec<u32> {
+    games
+        .iter()
+        .filter(|game| game.is_possible(bag))
+        .map(|game| game.id)
+        .collect::<Vec<u32>>()
+}
+
+/// What makes every game that isn't possible with `bag` impossible.
+pub fn find_rejections(games: &[Game], bag: &CubeSet) -> Vec<Rejection> {
+    games
+        .iter()
+        .enumerate()
+        .filter_map(|(index, game)| {
+            let violations = game.violations(bag);
+            (!violations.is_empty()).then(|| Rejection {
+                id: game.id,
+                line: index + 1,
+                violations,
+            })
+        })
+        .collect::<Vec<Rejection>>()
+}
+
+/// The power of every game's minimum set.
+pub fn find_minimum_sets(games: &[Game]) -> Result<Vec<u64>, Error> {
+    games
+        .iter()
+        .map(|game| {
+            game.minimum_set()
+                .power()
+                .ok_or(Error::PowerTooLarge(game.id))
+        })
+        .collect::<Result<Vec<u64>, Error>>()
+}
+
+/// The sum of the powers of every game's minimum set, the answer of part 2.
+pub fn total_power(games: &[Game]) -> Result<u64, Error> {
+    find_minimum_sets(games)?
+        .into_iter()
+        .try_fold(0u64, |total, power| total.checked_add(power))
+        .ok_or(Error::TotalPowerTooLarge)
+}
+
+/// The sum of game ids, the answer of part 1, wider than the ids
+/// themselves.
+pub fn sum_ids(ids: &[u32]) -> u64 {
+    ids.iter().map(|&id| u64::from(id)).sum()
+}
+
+/// The smallest bag every game is possible with: the most of each color
+/// any single draw showed.
+pub fn minimum_bag(games: &[Game]) -> CubeSet {
+    let mut bag = CubeSet::default();
+    for game in games {
+        bag.raise_to(&game.minimum_set());
+    }
+
+    bag
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+
+    const HAS_DETAILS: bool = true;
+
+    type Input<'a> = Vec<Game>;
+
+    type Error = Error;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Self::Error> {
+        parse_games(input)
+    }
+
+    fn part1(games: &Self::Input<'_>) -> Result<Answer, Self::Error> {
+        let possible_games = find_possible_games(games, &CubeSet::puzzle_bag());
+        Ok(sum_ids(&possible_games).into())
+    }
+
+    fn part2(games: &Self::Input<'_>) -> Result<Answer, Self::Error> {
+        Ok(total_power(games)?.into())
+    }
+
+    /// The id of every possible game for part 1, the power of every game's
+    /// minimum set for part 2.
+    fn details(games: &Self::Input<'_>, part: Part) -> Result<Vec<Detail>, Self::Error> {
+        let bag = CubeSet::puzzle_bag();
+        games
+            .iter()
+            .enumerate()
+            .map(|(index, game)| {
+                let value = match part {
+                    Part::One if game.is_possible(&bag) => u64::from(game.id),
+                    Part::One => 0,
+                    Part::Two => game
+                        .minimum_set()
+                        .power()
+                        .ok_or(Error::PowerTooLarge(game.id))?,
+                };
+                Ok(Detail::new(index + 1, value))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_possible_games_part_one() {
+        let input = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
+
+        let possible_games =
+            find_possible_games(&parse_games(input).unwrap(), &CubeSet::puzzle_bag());
+
+        assert_eq!(1, possible_games[0]);
+        assert_eq!(2, possible_games[1]);
+        assert_eq!(5, possible_games[2]);
+        assert_eq!(8, possible_games.iter().sum::<u32>());
+    }
+
+    #[test]
+    fn test_find_rejections_lists_the_violations() {
+        let input = "Game 1: 3 blue, 4 red\nGame 7: 20 red, 1 blue; 15 blue, 13 red";
+        let games = parse_games(input).unwrap();
+
+        let rejections = find_rejections(&games, &CubeSet::puzzle_bag());
+
+        assert_eq!(1, rejections.len());
+        assert_eq!((7, 2), (rejections[0].id, rejections[0].line));
+        assert_eq!(8, rejections[0].violations[0].excess());
+        assert_eq!(
+            "game 7 on line 2 is impossible
+  draw 1: 20 red, 8 more than the 12 in the bag
+  draw 2: 15 blue, 1 more than the 14 in the bag
+  draw 2: 13 red, 1 more than the 12 in the bag",
+            rejections[0].to_string()
+        );
+    }
+
+    #[test]
+    fn test_games_can_use_any_colors() {
+        let input = "Game 1: 3 yellow, 4 purple; 1 red\nGame 2: 2 purple";
+        let games = parse_games(input).unwrap();
+
+        assert_eq!(BTreeSet::from(["purple", "red", "yellow"]), colors(&games));
+        assert_eq!(Some(12), games[0].minimum_set().power());
+        assert!(games[0].is_possible(&CubeSet::parse_bag("yellow=3,purple=4,red=1").unwrap()));
+        assert!(!games[0].is_possible(&CubeSet::puzzle_bag()));
+    }
+
+    #[test]
+    fn test_parse_bag_and_check_games_against_it() {
+        let bag = CubeSet::parse_bag("red=20, green=5,blue=9").unwrap();
+        assert_eq!(5, bag.count("green"));
+
+        let bag = CubeSet::parse_bag("# a bag\nred = 20\n\nblue = 9").unwrap();
+        assert_eq!((20, 0), (bag.count("red"), bag.count("green")));
+        let games = parse_games("Game 1: 3 blue, 15 red\nGame 2: 1 green").unwrap();
+        assert_eq!(vec![1], find_possible_games(&games, &bag));
+
+        let error = Error::InvalidBagEntry(Location::new(1, 8, "green"));
+        assert_eq!(Err(error), CubeSet::parse_bag("red=1, green"));
+        let error = Error::InvalidColor(Location::new(1, 1, "p1nk"));
+        assert_eq!(Err(error), CubeSet::parse_bag("p1nk=1"));
+        assert_eq!(Err(Error::EmptyBag), CubeSet::parse_bag("# nothing"));
+    }
+
+    #[test]
+    fn test_get_possible_games_part_two() {
+        let input = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
+
+        let possible_games = find_minimum_sets(&parse_games(input).unwrap()).unwrap();
+
+        assert_eq!(2286, possible_games.iter().sum::<u64>());
+    }
+
+    #[test]
+    fn test_powers_of_many_colors_are_checked() {
+        let input = "Game 1: 300 red, 400 green, 500 blue, 600 yellow
+Game 2: 300 red, 400 green, 500 blue, 600 yellow, 70000 pink, 80000 cyan
+Game 3: 2147483648 red, 2147483648 blue; 2 green";
+        let games = parse_games(input).unwrap();
+
+        assert_eq!(Some(36_000_000_000), games[0].minimum_set().power());
+        assert_eq!(36_000_000_000, total_power(&games[..1]).unwrap());
+        assert_eq!(Err(Error::PowerTooLarge(2)), find_minimum_sets(&games[..2]));
+
+        // 2^63 each, the sum of two wraps
+        let large = vec![games[2].clone(); 2];
+        assert_eq!(Ok(1 << 63), total_power(&large[..1]));
+        assert_eq!(Err(Error::TotalPowerTooLarge), total_power(&large));
+    }
+
+    #[test]
+    fn test_details_give_what_every_game_adds() {
+        let input = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red";
+
+        let games = Day2::parse(input).unwrap();
+
+        let details = Day2::details(&games, Part::One).unwrap();
+        assert_eq!(vec![Detail::new(1, 1u32), Detail::new(2, 0u32)], details);
+
+        let details = Day2::details(&games, Part::Two).unwrap();
+        assert_eq!(
+            vec![Detail::new(1, 48u32), Detail::new(2, 1560u32)],
+            details
+        );
+    }
+
+    #[test]
+    fn test_parse_game_keeps_every_draw() {
+        let game = Game::parse_game("Game 3: 8 green, 6 blue; 5 blue, 4 red", 1).unwrap();
+
+        assert_eq!(3, game.id);
+        assert_eq!(2, game.draws.len());
+        assert_eq!(8, game.draws[0].cubes.count("green"));
+        assert_eq!(0, game.draws[1].cubes.count("green"));
+        assert_eq!(Some(4 * 8 * 6), game.minimum_set().power());
+    }
+
+    #[test]
+    fn test_parse_games_reports_malformed_games() {
+        let input = "Game 1: 3 blue, 4 red\nGame 2: 1 blue, green";
+        let error = Error::MissingCount(Location::new(2, 17, "1 blue, green"));
+        assert_eq!(Err(error), parse_games(input));
+
+        let input = "Game 1: 3 blue, 4 light blue; 1 red";
+        let error = Error::InvalidColor(Location::new(1, 19, "light blue"));
+        assert_eq!(Err(error), parse_games(input));
+
+        let input = "Game 1: 3 blue, 4";
+        let error = Error::MissingColor(Location::new(1, 17, "3 blue, 4"));
+        assert_eq!(Err(error), parse_games(input));
+
+        let input = "Game 1 3 blue";
+        let error = Error::MissingColon(Location::new(1, 1, "Game 1 3 blue"));
+        assert_eq!(Err(error), parse_games(input));
+
+        let input = "Game 1: 4000000000 red, 4000000000 red";
+        let error = Error::TooManyCubes(Location::new(1, 36, "red"));
+        assert_eq!(Err(error), parse_games(input));
+    }
+}
@@ -1,198 +1,219 @@
-use std::{env, error::Error, fs, collections::HashMap};
+use std::{env, error::Error, process::ExitCode};
+
+use common::{cli::Cli, exit_code, input_label, read_input};
+use day2::{
+    colors,
+    estimate::{estimate_bag, estimate_shares, EstimateError},
+    find_possible_games, find_rejections,
+    generate::{generate_games, parse_range, Settings},
+    minimum_bag, parse_games, sum_ids, total_power, CubeSet, Game,
+};
+
+const DEFAULT_GAMES: u32 = 100;
+
+/// A bag given as `<color>=<count>` entries or as a file of them.
+fn read_bag(bag: &str) -> Result<CubeSet, Box<dyn Error>> {
+    if bag.contains('=') {
+        return Ok(CubeSet::parse_bag(bag)?);
+    }
 
-#[derive(PartialEq, Eq, Hash, Debug)]
-enum Color {
-    RED,
-    BLUE,
-    GREEN,
+    Ok(CubeSet::parse_bag(&read_input(bag, false)?)
+        .map_err(|error| format!("{}: {}", bag, error))?)
 }
 
-impl Color {
-    fn map_str_to_color(color_as_str: &str) -> Option<Self> {
-        match color_as_str {
-            "red" => Some(Color::RED),
-            "blue" => Some(Color::BLUE),
-            "green" => Some(Color::GREEN),
-            _ => None,
-        }
+/// Writes random games played with a bag to stdout, and what the parts
+/// should answer for them to stderr.
+fn generate(args: &[String]) -> Result<(), Box<dyn Error>> {
+    let args = Cli::new("day2 generate", "Writes random games played with a bag")
+        .option(
+            "--bag",
+            "<bag|file>",
+            "bag to play with, 12 red, 13 green and 14 blue cubes by default",
+        )
+        .option("--games", "<n>", "number of games, 100 by default")
+        .option("--draws", "<n|min-max>", "draws per game, 1-6 by default")
+        .option("--cubes", "<n|min-max>", "cubes per draw, 1-10 by default")
+        .option("--seed", "<n>", "seed of the random numbers, 1 by default")
+        .parse(args)?;
+
+    let bag = match args.value("--bag") {
+        None => CubeSet::puzzle_bag(),
+        Some(bag) => read_bag(bag)?,
+    };
+    let range = |option: &str, default: &str| {
+        let value = args.value(option).unwrap_or(default);
+        parse_range(value)
+            .ok_or_else(|| args.error(format!("invalid value for {}: {}", option, value)))
+    };
+    let settings = Settings {
+        games: args.parse::<u32>("--games")?.unwrap_or(DEFAULT_GAMES),
+        draws: range("--draws", "1-6")?,
+        cubes: range("--cubes", "1-10")?,
+        seed: args.parse::<u64>("--seed")?.unwrap_or(1),
+    };
+
+    let games = generate_games(&bag, &settings)?;
+    for game in &games {
+        println!("{}", game);
     }
+    eprintln!(
+        "part1 total {}, part2 total {}",
+        sum_ids(&find_possible_games(&games, &bag)),
+        total_power(&games)?
+    );
 
-    fn is_valid(&self, digit: u32) -> bool {
-        match &self {
-            Color::RED => digit <= 12,
-            Color::BLUE => digit <= 14,
-            Color::GREEN => digit <= 13,
-        }
-    }
-}
-
-struct Game {
-    id: u32,
-    end_offset: usize,
+    Ok(())
 }
 
-impl Game {
-    fn parse_game(line: &str) -> Self {
-        let mut end_offset: Option<usize> = None;
-        let mut game_id_as_string = String::new();
-        for (index, character) in line.char_indices() {
-            if character.is_digit(10) {
-                game_id_as_string.push(character);
-            } else if character == ':' {
-                end_offset = Some(index);
-                break;
-            }
-        }
-
-        /*
-         * All games are going to be the same
-         * and for that reason, the unwrap is never going to fail
-         * */
-        let id = game_id_as_string.parse::<u32>().unwrap();
+fn run() -> Result<(), Box<dyn Error>> {
+    let args: Vec<String> = env::args().collect();
+    if args.get(1).map(String::as_str) == Some("generate") {
+        return generate(&args[2..]);
+    }
 
-        Game {
-            id,
-            end_offset: end_offset.unwrap(),
+    let args = Cli::new(
+        "day2",
+        "Day 2: Cube Conundrum\n\n`day2 generate` writes random games, see `day2 generate --help`",
+    )
+    .command("part1", "sum of the ids of the games possible with the bag")
+    .command("part2", "sum of the powers of the minimum sets")
+    .command(
+        "estimate",
+        "most likely share of every color of the bag, with 95% intervals",
+    )
+    .option(
+        "--bag",
+        "<bag|file>",
+        "bag of part1, 12 red, 13 green and 14 blue cubes by default",
+    )
+    .flag("--violations", "list why every impossible game of part1 is")
+    .option(
+        "--bag-size",
+        "<n>",
+        "cubes in the bag of estimate, which then gives counts instead of shares",
+    )
+    .inputs()
+    .parse(&args[1..])?;
+
+    let command = args.command.as_deref().unwrap_or_default();
+    let bag = match args.value("--bag") {
+        None => CubeSet::puzzle_bag(),
+        Some(_) if command != "part1" => {
+            return Err(args.error(format!("{} doesn't take --bag", command)).into())
         }
+        Some(bag) => read_bag(bag)?,
+    };
+    if args.flag("--violations") && command != "part1" {
+        let message = format!("{} doesn't take --violations", command);
+        return Err(args.error(message).into());
+    }
+    let bag_size = args.parse::<u64>("--bag-size")?;
+    if bag_size.is_some() && command != "estimate" {
+        let message = format!("{} doesn't take --bag-size", command);
+        return Err(args.error(message).into());
     }
-}
-
-fn find_possible_games(input: &str) -> Vec<u32> {
-    input
-        .lines()
-        .filter_map(|line| {
-            // find game id
-            let game = Game::parse_game(line);
-
-            let mut substring = String::new();
-            let line_with_only_colors = &line[game.end_offset + 2..];
-            for (i, character) in line_with_only_colors.char_indices() {
-                substring.push(character);
-                if character == ';' || i == line_with_only_colors.len() - 1 {
-                    let mut digit: u32 = 0;
-                    let mut digit_as_str = String::new();
-                    let mut color_as_str = String::new();
-                    for substring_character in substring.clone().chars() {
-                        if substring_character.is_digit(10) {
-                            digit_as_str.push(substring_character);
-                        }
-
-                        if substring_character.is_alphabetic() {
-                            digit = digit_as_str.parse::<u32>().unwrap();
-                            color_as_str.push(substring_character);
-
-                            if let Some(color) = Color::map_str_to_color(&color_as_str) {
-                                if !color.is_valid(digit) {
-                                    return None;
-                                }
-
-                                color_as_str.clear();
-                                digit_as_str.clear();
-                            }
-                        }
-                    }
 
-                    substring.clear();
+    let paths = &args.inputs;
+    for path in paths {
+        let input = read_input(path, args.flag("--strict"))?;
+        let games = parse_games(&input).map_err(|error| format!("{}: {}", path, error))?;
+        let label = input_label(path, paths.len());
+
+        let total = match command {
+            "part1" => {
+                // a color the bag lacks makes any game showing it impossible
+                let missing = colors(&games)
+                    .into_iter()
+                    .filter(|&color| bag.count(color) == 0)
+                    .collect::<Vec<&str>>();
+                if !missing.is_empty() {
+                    eprintln!(
+                        "warning: {}no {} cubes in the bag",
+                        label,
+                        missing.join(", ")
+                    );
                 }
-            }
 
-            Some(game.id)
-        })
-        .collect::<Vec<u32>>()
-}
-
-fn find_minimum_sets(input: &str) -> Vec<u32> {
-    input
-        .lines()
-        .filter_map(|line| {
-            // find game id
-            let game = Game::parse_game(line);
-
-            let mut color_map: HashMap<Color, u32> = HashMap::new();
-            let mut substring = String::new();
-            let line_with_only_colors = &line[game.end_offset + 2..];
-            for (i, character) in line_with_only_colors.char_indices() {
-                substring.push(character);
-                if character == ';' || i == line_with_only_colors.len() - 1 {
-                    let mut digit: u32 = 0;
-                    let mut digit_as_str = String::new();
-                    let mut color_as_str = String::new();
-                    for substring_character in substring.clone().chars() {
-                        if substring_character.is_digit(10) {
-                            digit_as_str.push(substring_character);
-                        }
-
-                        if substring_character.is_alphabetic() {
-                            digit = digit_as_str.parse::<u32>().unwrap();
-                            color_as_str.push(substring_character);
-
-                            if let Some(color) = Color::map_str_to_color(&color_as_str) {
-                                if let Some(value) = color_map.get(&color) {
-                                    let max_value = (*value).max(digit);
-                                    color_map.insert(color, max_value);
-                                } else {
-                                    color_map.insert(color, digit);
-                                }
-
-                                color_as_str.clear();
-                                digit_as_str.clear();
-                            }
-                        }
+                if args.flag("--violations") {
+                    for rejection in find_rejections(&games, &bag) {
+                        println!("{}{}", label, rejection);
                     }
-
-                    substring.clear();
                 }
-            }
 
-            
-            Some(color_map.values().product())
-        })
-        .collect::<Vec<u32>>()
-}
-
-fn main() -> Result<(), Box<dyn Error>> {
-    let args: Vec<String> = env::args().collect();
-    if args.len() > 1 {
-        let input = fs::read_to_string(&args[1])?;
-        // let possible_games = find_possible_games(&input); // part 1
-        let minimum_sets = find_minimum_sets(&input);
+                sum_ids(&find_possible_games(&games, &bag))
+            }
+            "part2" => total_power(&games).map_err(|error| format!("{}: {}", path, error))?,
+            "estimate" => {
+                print_estimates(&games, bag_size, &label)
+                    .map_err(|error| format!("{}: {}", path, error))?;
+                continue;
+            }
+            _ => unreachable!("the command line only takes part1, part2 and estimate"),
+        };
 
-        println!("total {}", minimum_sets.iter().sum::<u32>());
+        println!("{}total {}", label, total);
     }
 
     Ok(())
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_get_possible_games_part_one() {
-        let input = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
-Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
-Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
-Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
-Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
-
-        let possible_games = find_possible_games(input);
-
-        assert_eq!(1, possible_games[0]);
-        assert_eq!(2, possible_games[1]);
-        assert_eq!(5, possible_games[2]);
-        assert_eq!(8, possible_games.iter().sum::<u32>());
+/// A table of the estimated share of every color, or its number of cubes
+/// when the size of the bag is given, next to the fewest cubes of it the
+/// games need.
+fn print_estimates(
+    games: &[Game],
+    bag_size: Option<u64>,
+    label: &str,
+) -> Result<(), EstimateError> {
+    let minimum_bag = minimum_bag(games);
+    let Some(size) = bag_size else {
+        let shares = estimate_shares(games);
+        let seen = shares.iter().map(|share| share.seen).sum::<u64>();
+
+        println!("{}{} cubes seen, 95% intervals", label, seen);
+        println!(
+            "{}{:<10}{:>8} {:>14} {:>8}",
+            label, "color", "share", "interval", "minimum"
+        );
+        for share in &shares {
+            println!(
+                "{}{:<10}{:>8.3} {:>14} {:>8}",
+                label,
+                share.color,
+                share.share,
+                format!("{:.3}..{:.3}", share.low, share.high),
+                minimum_bag.count(&share.color)
+            );
+        }
+        return Ok(());
+    };
+
+    let estimates = estimate_bag(games, size)?;
+    let seen = estimates.iter().map(|estimate| estimate.seen).sum::<u64>();
+
+    println!(
+        "{}{} cubes seen, bag of {} cubes, 95% intervals",
+        label, seen, size
+    );
+    println!(
+        "{}{:<10}{:>8} {:>16} {:>7} {:>8}",
+        label, "color", "cubes", "interval", "share", "minimum"
+    );
+    for estimate in &estimates {
+        println!(
+            "{}{:<10}{:>8} {:>16} {:>7.3} {:>8}",
+            label,
+            estimate.color,
+            estimate.cubes,
+            format!("{}..{}", estimate.low, estimate.high),
+            estimate.share(size),
+            estimate.minimum
+        );
     }
 
-    #[test]
-    fn test_get_possible_games_part_two() {
-        let input = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
-Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
-Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
-Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
-Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
-
-        let possible_games = find_minimum_sets(input);
+    Ok(())
+}
 
-        assert_eq!(2286, possible_games.iter().sum::<u32>());
-    }
+fn main() -> ExitCode {
+    exit_code(run())
 }
@@ -0,0 +1,20 @@
+use day2::{colors, is_color, CubeSet, Game};
+
+#[test]
+fn test_parse_game_and_check_colors_against_the_bag() {
+    let game = Game::parse_game("Game 12: 3 blue, 4 red; 13 red", 1).unwrap();
+    assert_eq!(12, game.id);
+    assert_eq!(2, game.draws.len());
+
+    let bag = CubeSet::puzzle_bag();
+    assert!(game.draws[0].cubes.is_possible(&bag));
+    assert!(!game.is_possible(&bag));
+    assert!(game.is_possible(&CubeSet::parse_bag("red=13,blue=3").unwrap()));
+
+    assert_eq!(
+        vec!["blue", "red"],
+        colors(&[game]).into_iter().collect::<Vec<_>>()
+    );
+    assert!(is_color("yellow"));
+    assert!(!is_color("yel low"));
+}
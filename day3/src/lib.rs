@@ -0,0 +1,302 @@
+use std::{
+    collections::{HashMap, HashSet},
+    error, fmt,
+};
+
+use common::{Answer, Location, Solution};
+
+const DIRECTIONS: [(i32, i32); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Error {
+    EmptySchematic,
+    RaggedRow { location: Location, expected: usize },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::EmptySchematic => write!(f, "the schematic is empty"),
+            Error::RaggedRow { location, expected } => write!(
+                f,
+                "{}: expected a row of {} characters, got `{}`",
+                location, expected, location.text
+            ),
+        }
+    }
+}
+
+impl error::Error for Error {}
+
+#[derive(Hash, PartialEq, Eq, Debug)]
+pub struct Point {
+    pub y: usize,
+    pub x_start: usize,
+    pub x_end: Option<usize>,
+}
+
+#[derive(Hash, PartialEq, Eq, Debug)]
+pub struct Symbol {
+    pub value: char,
+    pub point: Point,
+}
+
+#[derive(Debug)]
+pub struct Number {
+    pub value: u32,
+    pub point: Point,
+}
+
+impl Number {
+    pub fn new(v: u32, x_start: usize, y: usize) -> Self {
+        Self {
+            value: v,
+            point: Point {
+                y,
+                x_start,
+                x_end: None,
+            },
+        }
+    }
+
+    pub fn concat_digit(&mut self, n: u32) {
+        self.value = self.value * 10 + n
+    }
+
+    pub fn get_adjacent_symbols(
+        &self,
+        schematic: &[Vec<char>],
+        schematic_dimensions: (usize, usize),
+    ) -> HashSet<Symbol> {
+        let (schematic_y_dimension, schematic_x_dimension) = schematic_dimensions;
+        let number_x_start = self.point.x_start as i32;
+        let number_x_end = self.point.x_end.unwrap() as i32 + 1;
+
+        let mut adjacent_symbols: HashSet<Symbol> = HashSet::new();
+
+        for tmp_x in number_x_start..number_x_end {
+            for (dy, dx) in DIRECTIONS {
+                let y = dy + self.point.y as i32;
+                let x = tmp_x + dx;
+
+                if y < 0
+                    || y >= schematic_y_dimension as i32
+                    || x < 0
+                    || x >= schematic_x_dimension as i32
+                {
+                    continue;
+                }
+
+                let chararacter = schematic[y as usize][x as usize];
+                if is_symbol(chararacter) {
+                    adjacent_symbols.insert(Symbol {
+                        value: chararacter,
+                        point: Point {
+                            y: y as usize,
+                            x_start: x as usize,
+                            x_end: Some(x as usize),
+                        },
+                    });
+                }
+            }
+        }
+
+        adjacent_symbols
+    }
+}
+
+fn is_dot(c: char) -> bool {
+    c == '.'
+}
+
+fn is_digit(c: char) -> bool {
+    c.is_ascii_digit()
+}
+
+pub fn is_symbol(c: char) -> bool {
+    !is_dot(c) && !is_digit(c)
+}
+
+fn to_digit(c: char) -> u32 {
+    c.to_digit(10).unwrap()
+}
+
+pub fn generate_schematic(input: &str) -> Result<Vec<Vec<char>>, Error> {
+    let schematic = input
+        .lines()
+        .map(|line| line.chars().collect::<Vec<char>>())
+        .collect::<Vec<Vec<char>>>();
+
+    // every row is indexed with the width of the first one
+    let width = schematic.first().ok_or(Error::EmptySchematic)?.len();
+    if let Some((y, line)) = input
+        .lines()
+        .enumerate()
+        .find(|(y, _)| schematic[*y].len() != width)
+    {
+        let column = schematic[y].len().min(width) + 1;
+        return Err(Error::RaggedRow {
+            location: Location::new(y + 1, column, line),
+            expected: width,
+        });
+    }
+
+    Ok(schematic)
+}
+
+pub fn get_schematic_dimensions(schematic: &[Vec<char>]) -> (usize, usize) {
+    (schematic.len(), schematic[0].len())
+}
+
+pub fn get_parsed_numbers(
+    schematic: &[Vec<char>],
+    schematic_dimensions: (usize, usize),
+) -> Vec<Number> {
+    let (_, x_len) = schematic_dimensions;
+    let mut parsed_numbers: Vec<Number> = vec![];
+    for (y, row) in schematic.iter().enumerate() {
+        let mut number: Option<Number> = None;
+        for (x, &chararacter) in row.iter().enumerate().take(x_len) {
+            if is_digit(chararacter) && number.is_none() {
+                number = Some(Number::new(to_digit(chararacter), x, y));
+            } else if is_digit(chararacter) && number.is_some() {
+                let mut tmp = number.unwrap();
+                tmp.concat_digit(to_digit(chararacter));
+                number = Some(tmp)
+            }
+
+            // x == x_len -1 means that we reach eof :S
+            if (is_dot(chararacter) || is_symbol(chararacter) || x == x_len - 1) && number.is_some()
+            {
+                let mut tmp = number.unwrap();
+                tmp.point.x_end = Some(if x == x_len - 1 { x } else { x - 1 });
+                parsed_numbers.push(tmp);
+                number = None
+            }
+        }
+    }
+
+    parsed_numbers
+}
+
+pub fn find_adjacent_numbers_to_symbol(schematic: &[Vec<char>]) -> Vec<u32> {
+    let schematic_dimensions = get_schematic_dimensions(schematic);
+    let mut numbers_adjacent_to_symbols: Vec<u32> = vec![];
+
+    let numbers = get_parsed_numbers(schematic, schematic_dimensions);
+    for number in numbers {
+        let adjacent_symbols = number.get_adjacent_symbols(schematic, schematic_dimensions);
+        if !adjacent_symbols.is_empty() {
+            numbers_adjacent_to_symbols.push(number.value);
+        }
+    }
+
+    numbers_adjacent_to_symbols
+}
+
+pub fn find_gear_ratios(schematic: &[Vec<char>]) -> Vec<u32> {
+    let schematic_dimensions = get_schematic_dimensions(schematic);
+    let numbers = get_parsed_numbers(schematic, schematic_dimensions);
+
+    let mut gear_ratios_by_start_symbol: HashMap<Symbol, Vec<u32>> = HashMap::new();
+
+    for number in numbers {
+        let adjacent_symbols = number.get_adjacent_symbols(schematic, schematic_dimensions);
+        for symbol in adjacent_symbols {
+            if symbol.value != '*' {
+                continue;
+            }
+
+            (*gear_ratios_by_start_symbol.entry(symbol).or_default()).push(number.value);
+        }
+    }
+
+    gear_ratios_by_start_symbol
+        .values()
+        .filter_map(|vec| {
+            if vec.len() > 1 {
+                Some(vec.iter().product())
+            } else {
+                None
+            }
+        })
+        .collect::<Vec<u32>>()
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+
+    type Input<'a> = Vec<Vec<char>>;
+
+    type Error = Error;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Self::Error> {
+        generate_schematic(input) // generating the matrix
+    }
+
+    fn part1(schematic: &Self::Input<'_>) -> Result<Answer, Self::Error> {
+        Ok(find_adjacent_numbers_to_symbol(schematic)
+            .iter()
+            .sum::<u32>()
+            .into())
+    }
+
+    fn part2(schematic: &Self::Input<'_>) -> Result<Answer, Self::Error> {
+        Ok(find_gear_ratios(schematic).iter().sum::<u32>().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_adjacent_numbers_to_symbol() {
+        let input = "467..114..\n...*......\n..35..633.\n......#...\n617*......\n.....+.58.\n..592.....\n......755.\n...$.*....\n.664.598..";
+
+        let adjacent_numbers_to_symbol =
+            find_adjacent_numbers_to_symbol(&generate_schematic(input).unwrap());
+
+        assert_eq!(467, adjacent_numbers_to_symbol[0]);
+        assert_eq!(35, adjacent_numbers_to_symbol[1]);
+        assert_eq!(633, adjacent_numbers_to_symbol[2]);
+        assert_eq!(617, adjacent_numbers_to_symbol[3]);
+        assert_eq!(592, adjacent_numbers_to_symbol[4]);
+        assert_eq!(755, adjacent_numbers_to_symbol[5]);
+        assert_eq!(664, adjacent_numbers_to_symbol[6]);
+        assert_eq!(598, adjacent_numbers_to_symbol[7]);
+        assert_eq!(4361, adjacent_numbers_to_symbol.iter().sum::<u32>());
+    }
+
+    #[test]
+    fn test_find_gear_ratios() {
+        let input = "467..114..\n...*......\n..35..633.\n......#...\n617*......\n.....+.58.\n..592.....\n......755.\n...$.*....\n.664.598..";
+
+        let adjacent_numbers_to_symbol = find_gear_ratios(&generate_schematic(input).unwrap());
+
+        assert_eq!(467835, adjacent_numbers_to_symbol.iter().sum::<u32>());
+    }
+
+    #[test]
+    fn test_generate_schematic_rejects_rows_of_different_width() {
+        let input = "467..114..\n...*......\n..35..633";
+
+        let error = Error::RaggedRow {
+            location: Location::new(3, 10, "..35..633"),
+            expected: 10,
+        };
+        assert_eq!(Err(error), generate_schematic(input));
+        assert_eq!(Err(Error::EmptySchematic), generate_schematic(""));
+    }
+}
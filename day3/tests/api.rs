@@ -0,0 +1,20 @@
+use day3::{generate_schematic, get_parsed_numbers, get_schematic_dimensions};
+
+#[test]
+fn test_numbers_know_which_symbols_are_next_to_them() {
+    let schematic = generate_schematic("467..114..\n...*......\n..35..633.").unwrap();
+    let dimensions = get_schematic_dimensions(&schematic);
+
+    let numbers = get_parsed_numbers(&schematic, dimensions);
+    let values = numbers
+        .iter()
+        .map(|number| number.value)
+        .collect::<Vec<_>>();
+    assert_eq!(vec![467, 114, 35, 633], values);
+
+    let symbols = numbers[0].get_adjacent_symbols(&schematic, dimensions);
+    assert_eq!(1, symbols.len());
+    assert!(numbers[1]
+        .get_adjacent_symbols(&schematic, dimensions)
+        .is_empty());
+}
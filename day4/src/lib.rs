@@ -0,0 +1,221 @@
+use std::{collections::HashSet, error, fmt};
+
+use common::{split_with_offsets, Answer, Location, Solution};
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Error {
+    MissingColon(Location),
+    MissingSeparator(Location),
+    InvalidNumber(Location),
+    TooManyCopies(i32),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::MissingColon(location) => {
+                write!(
+                    f,
+                    "{}: expected `Card <id>: ...`, got `{}`",
+                    location, location.text
+                )
+            }
+            Error::MissingSeparator(location) => write!(
+                f,
+                "{}: expected `|` between the winning numbers and yours in `{}`",
+                location, location.text
+            ),
+            Error::InvalidNumber(location) => {
+                write!(f, "{}: invalid number `{}`", location, location.text)
+            }
+            Error::TooManyCopies(id) => {
+                write!(f, "card {} wins copies of cards past the last one", id)
+            }
+        }
+    }
+}
+
+impl error::Error for Error {}
+
+/// The numbers of `numbers`, which starts at byte `offset` of its line,
+/// separated by spaces.
+fn parse_numbers(numbers: &str, location: (usize, usize)) -> Result<HashSet<u32>, Error> {
+    let (line_number, offset) = location;
+    split_with_offsets(numbers, ' ')
+        .filter(|&(_, el)| !el.is_empty())
+        .map(|(column, el)| {
+            el.parse::<u32>().map_err(|_| {
+                Error::InvalidNumber(Location::new(line_number, offset + column + 1, el))
+            })
+        })
+        .collect::<Result<HashSet<u32>, Error>>()
+}
+
+pub fn get_winning_and_my_numbers(
+    line: &str,
+    line_number: usize,
+) -> Result<(HashSet<u32>, HashSet<u32>), Error> {
+    let colon_index = line
+        .find(':')
+        .ok_or_else(|| Error::MissingColon(Location::new(line_number, 1, line)))?;
+    let numbers_index = colon_index + 1;
+    let (winning_numbers, my_numbers) = line[numbers_index..].split_once('|').ok_or_else(|| {
+        Error::MissingSeparator(Location::new(line_number, colon_index + 1, line))
+    })?;
+
+    let separator_index = numbers_index + winning_numbers.len();
+    Ok((
+        parse_numbers(winning_numbers, (line_number, numbers_index))?,
+        parse_numbers(my_numbers, (line_number, separator_index + 1))?,
+    ))
+}
+
+pub fn get_matched_numbers(line: &str, line_number: usize) -> Result<HashSet<u32>, Error> {
+    let (winning_numbers, my_numbers) = get_winning_and_my_numbers(line, line_number)?;
+    Ok(winning_numbers
+        .intersection(&my_numbers)
+        .cloned()
+        .collect::<HashSet<_>>())
+}
+
+pub fn get_points_the_cards_woth(cards: &[Card]) -> Vec<u32> {
+    cards
+        .iter()
+        .map(|card| {
+            let mut points = 0;
+            for _ in &card.matched_numbers {
+                if points == 0 {
+                    points += 1
+                } else {
+                    points <<= 1
+                }
+            }
+
+            points
+        })
+        .collect::<Vec<u32>>()
+}
+
+#[derive(Clone, Debug)]
+pub struct Card {
+    pub matched_numbers: HashSet<u32>,
+    pub id: i32,
+    /// How many copies of the card there are, the original included.
+    pub ref_count: u32,
+}
+
+pub fn parse_cards(input: &str) -> Result<Vec<Card>, Error> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| {
+            Ok(Card {
+                id: index as i32 + 1,
+                ref_count: 1,
+                matched_numbers: get_matched_numbers(line, index + 1)?,
+            })
+        })
+        .collect::<Result<Vec<_>, Error>>()
+}
+
+pub fn get_total_scratchcards(cards: &[Card]) -> Result<u32, Error> {
+    let mut cards = cards.to_vec();
+
+    for i in 0..cards.len() {
+        let card = cards[i].clone();
+        let total_matches = card.matched_numbers.len() as i32;
+        if (card.id + total_matches) as usize > cards.len() {
+            return Err(Error::TooManyCopies(card.id));
+        }
+
+        for _ in 0..card.ref_count {
+            for i in card.id..card.id + total_matches {
+                cards[i as usize].ref_count += 1;
+            }
+        }
+    }
+
+    Ok(cards.iter().map(|card| card.ref_count).sum::<u32>())
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+
+    type Input<'a> = Vec<Card>;
+
+    type Error = Error;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Self::Error> {
+        parse_cards(input)
+    }
+
+    fn part1(cards: &Self::Input<'_>) -> Result<Answer, Self::Error> {
+        Ok(get_points_the_cards_woth(cards).iter().sum::<u32>().into())
+    }
+
+    fn part2(cards: &Self::Input<'_>) -> Result<Answer, Self::Error> {
+        Ok(get_total_scratchcards(cards)?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_points_the_cards_woth() {
+        let input = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
+
+        let points = get_points_the_cards_woth(&parse_cards(input).unwrap());
+
+        assert_eq!(13, points.iter().sum::<u32>())
+    }
+
+    #[test]
+    fn test_get_total_scratchcards() {
+        let input = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
+
+        let points = get_total_scratchcards(&parse_cards(input).unwrap()).unwrap();
+
+        assert_eq!(30, points);
+
+        let cards = parse_cards("Card 1: 1 2 | 1 2\nCard 2: 1 | 1").unwrap();
+        assert_eq!(Err(Error::TooManyCopies(1)), get_total_scratchcards(&cards));
+    }
+
+    #[test]
+    fn test_parse_cards_reports_malformed_cards() {
+        let input = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53\n\nCard 3: 1 21 53";
+
+        let error = Error::MissingColon(Location::new(2, 1, ""));
+        assert_eq!(error, parse_cards(input).unwrap_err());
+
+        let error = Error::MissingSeparator(Location::new(1, 7, "Card 1: 1 21 53"));
+        assert_eq!(error, parse_cards("Card 1: 1 21 53").unwrap_err());
+
+        let (winning, mine) = get_winning_and_my_numbers("Card 1: 4|1  7", 1).unwrap();
+        assert_eq!((HashSet::from([4]), HashSet::from([1, 7])), (winning, mine));
+
+        let error = Error::InvalidNumber(Location::new(1, 9, "éé"));
+        assert_eq!(error, parse_cards("Card 1: éé 4| 1é").unwrap_err());
+        let error = Error::InvalidNumber(Location::new(1, 9, "4x5"));
+        assert_eq!(error, parse_cards("Card 1: 4x5 -7 | 5 7 zz").unwrap_err());
+        let error = Error::InvalidNumber(Location::new(1, 21, "zz"));
+        assert_eq!(error, parse_cards("Card 1: 4 5 7 | 5 7 zz").unwrap_err());
+
+        let error = Error::InvalidNumber(Location::new(1, 13, "99999999999"));
+        assert_eq!(error, parse_cards("Card 1: 1 | 99999999999").unwrap_err());
+    }
+}
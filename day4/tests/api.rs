@@ -0,0 +1,20 @@
+use std::collections::HashSet;
+
+use day4::{get_matched_numbers, parse_cards};
+
+#[test]
+fn test_matched_numbers_of_a_card() {
+    let matched_numbers =
+        get_matched_numbers("Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53", 1);
+
+    assert_eq!(HashSet::from([48, 83, 86, 17]), matched_numbers.unwrap());
+
+    let cards = parse_cards("Card 1: 41 48 | 41 6\nCard 2: 1 | 2").unwrap();
+    assert_eq!(
+        vec![1, 0],
+        cards
+            .iter()
+            .map(|card| card.matched_numbers.len())
+            .collect::<Vec<_>>()
+    );
+}
@@ -0,0 +1,479 @@
+use std::{
+    collections::{HashMap, HashSet},
+    error, fmt,
+    ops::Range,
+};
+
+use common::{split_with_offsets, Answer, Location, Solution};
+
+#[derive(Debug)]
+pub struct RangeMap {
+    pub source: u64,
+    pub destination: u64,
+    pub length: u64,
+}
+
+#[derive(Debug)]
+pub struct AlmanacMap<'a> {
+    pub from: &'a str,
+    pub to: &'a str,
+    pub range_maps: Vec<RangeMap>,
+}
+
+#[derive(Debug)]
+pub struct Almanac<'a> {
+    pub seeds: Vec<u64>,
+    /// Every map indexed by the category it converts from.
+    pub maps: HashMap<&'a str, AlmanacMap<'a>>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Error {
+    MissingSeeds(Location),
+    InvalidNumber(Location),
+    InvalidMapHeader(Location),
+    InvalidRange(Location),
+    UnpairedSeed(u64),
+    EmptySeedRange(u64),
+    CyclicMaps(String),
+    NumberTooLarge(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::MissingSeeds(location) => {
+                write!(
+                    f,
+                    "{}: expected `seeds: <numbers>`, got `{}`",
+                    location, location.text
+                )
+            }
+            Error::InvalidNumber(location) => {
+                write!(f, "{}: invalid number `{}`", location, location.text)
+            }
+            Error::InvalidMapHeader(location) => write!(
+                f,
+                "{}: expected `<from>-to-<to> map:`, got `{}`",
+                location, location.text
+            ),
+            Error::InvalidRange(location) => write!(
+                f,
+                "{}: expected `<destination> <source> <length>`, got `{}`",
+                location, location.text
+            ),
+            Error::UnpairedSeed(seed) => {
+                write!(f, "seed ranges come in pairs, {} has no length", seed)
+            }
+            Error::EmptySeedRange(seed) => {
+                write!(f, "the seed range starting at {} is empty", seed)
+            }
+            Error::CyclicMaps(category) => {
+                write!(f, "the maps go round in a loop through `{}`", category)
+            }
+            Error::NumberTooLarge(category) => {
+                write!(f, "a `{}` number doesn't fit in 64 bits", category)
+            }
+        }
+    }
+}
+
+impl error::Error for Error {}
+
+fn parse_number(n: &str, location: (usize, usize)) -> Result<u64, Error> {
+    let (line_number, column) = location;
+    n.parse::<u64>()
+        .map_err(|_| Error::InvalidNumber(Location::new(line_number, column, n)))
+}
+
+fn parse_numbers(input: &str, location: (usize, usize)) -> Result<Vec<u64>, Error> {
+    let (line_number, column) = location;
+    split_with_offsets(input, ' ')
+        .filter(|&(_, el)| !el.is_empty())
+        .map(|(offset, el)| parse_number(el, (line_number, column + offset)))
+        .collect::<Result<Vec<u64>, Error>>()
+}
+
+fn parse_map_header(unparsed_header: &str, line_number: usize) -> Result<(&str, &str), Error> {
+    unparsed_header
+        .split(' ')
+        .next()
+        .and_then(|header| header.split_once("-to-"))
+        .ok_or_else(|| Error::InvalidMapHeader(Location::new(line_number, 1, unparsed_header)))
+}
+
+fn parse_map_range_numbers(
+    unparsed_range_numbers: &[(usize, &str)],
+) -> Result<Vec<RangeMap>, Error> {
+    let mut range_maps: Vec<RangeMap> = vec![];
+    for &(line_number, unparsed_range) in unparsed_range_numbers {
+        let numbers = parse_numbers(unparsed_range, (line_number, 1))?;
+        if numbers.len() != 3 {
+            return Err(Error::InvalidRange(Location::new(
+                line_number,
+                1,
+                unparsed_range,
+            )));
+        }
+
+        range_maps.push(RangeMap {
+            source: numbers[1],
+            destination: numbers[0],
+            length: numbers[2],
+        });
+    }
+    Ok(range_maps)
+}
+
+fn parse_maps<'a>(input: &[(usize, &'a str)]) -> Result<HashMap<&'a str, AlmanacMap<'a>>, Error> {
+    let mut maps: HashMap<&'a str, AlmanacMap<'_>> = HashMap::new();
+    for &(line_number, map_as_str) in input.iter() {
+        let content = map_as_str
+            .split('\n')
+            .enumerate()
+            .map(|(index, l)| (line_number + index, l))
+            .filter(|&(_, l)| !l.is_empty())
+            .collect::<Vec<(usize, &str)>>();
+
+        // a run of blank lines leaves blocks with nothing in them
+        let Some(&(header_line_number, header)) = content.first() else {
+            continue;
+        };
+        let (from, to) = parse_map_header(header, header_line_number)?;
+        let range_maps = parse_map_range_numbers(&content[1..])?;
+
+        maps.insert(
+            from,
+            AlmanacMap {
+                from,
+                to,
+                range_maps,
+            },
+        );
+    }
+
+    Ok(maps)
+}
+
+/// Fails when following the maps from some category leads back to it, as
+/// `walk` would never end.
+fn check_for_cycles(maps: &HashMap<&str, AlmanacMap<'_>>) -> Result<(), Error> {
+    let mut starts = maps.keys().copied().collect::<Vec<&str>>();
+    starts.sort_unstable();
+    for start in starts {
+        let mut visited = HashSet::new();
+        let mut category = start;
+        while let Some(map) = maps.get(category) {
+            if !visited.insert(category) {
+                return Err(Error::CyclicMaps(category.to_string()));
+            }
+            category = map.to;
+        }
+    }
+
+    Ok(())
+}
+
+/// Follows `n` through the maps, starting at the `from` category, until
+/// there is no map left and returns the final number. The maps must not
+/// loop, which `parse_almanac` checks.
+pub fn walk(n: u64, maps: &HashMap<&'_ str, AlmanacMap<'_>>, from: &str) -> Result<u64, Error> {
+    match maps.get(from) {
+        Some(map) => {
+            if let Some(range) = map
+                .range_maps
+                .iter()
+                .find(|&range| range.source <= n && n < range.source.saturating_add(range.length))
+            {
+                let diff = n.abs_diff(range.source);
+                let n = range
+                    .destination
+                    .checked_add(diff)
+                    .ok_or_else(|| Error::NumberTooLarge(map.to.to_string()))?;
+                walk(n, maps, map.to)
+            } else {
+                walk(n, maps, map.to)
+            }
+        }
+        _ => Ok(n),
+    }
+}
+
+pub fn parse_almanac(input: &str) -> Result<Almanac<'_>, Error> {
+    // keep the line where every block starts to report errors
+    let mut line_number = 1;
+    let mut content: Vec<(usize, &str)> = vec![];
+    for block in input.split("\n\n") {
+        if !block.is_empty() {
+            content.push((line_number, block));
+        }
+        line_number += block.matches('\n').count() + 2;
+    }
+
+    let (seeds_line_number, seeds) = content.first().copied().unwrap_or((1, ""));
+    let seeds = match seeds.trim_end().strip_prefix("seeds:") {
+        Some(numbers) => parse_numbers(numbers, (seeds_line_number, 7))?,
+        None => vec![],
+    };
+    if seeds.is_empty() {
+        let line = input.lines().nth(seeds_line_number - 1).unwrap_or("");
+        return Err(Error::MissingSeeds(Location::new(
+            seeds_line_number,
+            1,
+            line,
+        )));
+    }
+
+    let maps = parse_maps(&content[1..])?;
+    check_for_cycles(&maps)?;
+
+    Ok(Almanac { seeds, maps })
+}
+
+pub fn find_lowest_location_number(almanac: &Almanac<'_>) -> Result<u64, Error> {
+    let mut min_loc: Option<u64> = None;
+    for &seed in almanac.seeds.iter() {
+        let loc = walk(seed, &almanac.maps, "seed")?;
+        if let Some(mloc) = min_loc {
+            min_loc = Some(if mloc < loc { mloc } else { loc })
+        } else {
+            min_loc = Some(loc)
+        }
+    }
+
+    // parsing makes sure there's at least one seed
+    Ok(min_loc.unwrap())
+}
+
+pub fn find_lowest_location_number_2(almanac: &Almanac<'_>) -> Result<u64, Error> {
+    if !almanac.seeds.len().is_multiple_of(2) {
+        return Err(Error::UnpairedSeed(almanac.seeds[almanac.seeds.len() - 1]));
+    }
+
+    let seed_ranges = almanac
+        .seeds
+        .chunks(2)
+        .map(|arr| {
+            let rstart = arr[0];
+            if arr[1] == 0 {
+                return Err(Error::EmptySeedRange(rstart));
+            }
+            let rend = arr[1]
+                .checked_add(rstart)
+                .ok_or_else(|| Error::NumberTooLarge("seed".to_string()))?;
+            Ok((rstart, rend))
+        })
+        .collect::<Result<Vec<(u64, u64)>, Error>>()?;
+
+    let mut min_loc: Option<u64> = None;
+    let mut last_range: Option<Range<u64>> = None;
+
+    for (seed_start, seed_end) in seed_ranges {
+        let range = match &last_range {
+            Some(last_range) => {
+                if (last_range.start > seed_end) || (last_range.end < seed_start) {
+                    seed_start..seed_end
+                } else if last_range.end > seed_start {
+                    last_range.end..seed_end
+                } else if last_range.start < seed_end {
+                    seed_start..last_range.start
+                } else {
+                    seed_start..seed_end
+                }
+            }
+            _ => seed_start..seed_end,
+        };
+
+        last_range = match &last_range {
+            Some(last_range) => {
+                if last_range.start > seed_end {
+                    Some(seed_start..last_range.end)
+                } else if last_range.end != seed_start {
+                    Some(last_range.start..seed_end)
+                } else if last_range.start < seed_end {
+                    Some(seed_start..last_range.start)
+                } else {
+                    Some(seed_start..seed_end)
+                }
+            }
+            _ => Some(seed_start..seed_end),
+        };
+
+        for seed in range {
+            let loc = walk(seed, &almanac.maps, "seed")?;
+            if let Some(mloc) = min_loc {
+                min_loc = Some(if mloc < loc { mloc } else { loc });
+            } else {
+                min_loc = Some(loc);
+            }
+        }
+    }
+
+    // no range is empty and the first one is always walked whole
+    Ok(min_loc.unwrap())
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+
+    type Input<'a> = Almanac<'a>;
+
+    type Error = Error;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Self::Error> {
+        parse_almanac(input)
+    }
+
+    fn part1(almanac: &Self::Input<'_>) -> Result<Answer, Self::Error> {
+        Ok(find_lowest_location_number(almanac)?.into())
+    }
+
+    fn part2(almanac: &Self::Input<'_>) -> Result<Answer, Self::Error> {
+        Ok(find_lowest_location_number_2(almanac)?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_lowest_location_number() {
+        let input = "seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4";
+
+        let lowest_location_number =
+            find_lowest_location_number(&parse_almanac(input).unwrap()).unwrap();
+
+        assert_eq!(35, lowest_location_number)
+    }
+
+    #[test]
+    fn test_find_lowest_location_number_2() {
+        let input = "seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4";
+
+        let lowest_location_number =
+            find_lowest_location_number_2(&parse_almanac(input).unwrap()).unwrap();
+
+        assert_eq!(46, lowest_location_number)
+    }
+
+    #[test]
+    fn test_seeds_that_go_nowhere() {
+        let almanac = parse_almanac("seeds: 79 0").unwrap();
+        let error = Error::EmptySeedRange(79);
+        assert_eq!(Err(error), find_lowest_location_number_2(&almanac));
+
+        let almanac = parse_almanac("seeds: 18446744073709551615 2").unwrap();
+        let error = Error::NumberTooLarge("seed".to_string());
+        assert_eq!(Err(error), find_lowest_location_number_2(&almanac));
+
+        let input = "seeds: 5\n\nseed-to-soil map:\n18446744073709551615 0 10";
+        let almanac = parse_almanac(input).unwrap();
+        let error = Error::NumberTooLarge("soil".to_string());
+        assert_eq!(Err(error), find_lowest_location_number(&almanac));
+
+        let input = "seeds: 18446744073709551614\n\nseed-to-soil map:\n0 1 18446744073709551615";
+        let almanac = parse_almanac(input).unwrap();
+        assert_eq!(
+            Ok(18446744073709551613),
+            find_lowest_location_number(&almanac)
+        );
+    }
+
+    #[test]
+    fn test_parse_almanac_reports_malformed_input() {
+        let input = "seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 5O 48";
+        let error = Error::InvalidNumber(Location::new(5, 4, "5O"));
+        assert_eq!(error, parse_almanac(input).unwrap_err());
+
+        let input = "seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98";
+        let error = Error::InvalidRange(Location::new(4, 1, "50 98"));
+        assert_eq!(error, parse_almanac(input).unwrap_err());
+
+        let almanac = parse_almanac("seeds: 1 2\n\n\n\n\n").unwrap();
+        assert!(almanac.maps.is_empty());
+
+        let input = "seeds: 1 2\n\nseed-to-a map:\n1 2 3\n\na-to-seed map:\n1 2 3";
+        let error = Error::CyclicMaps("a".to_string());
+        assert_eq!(error, parse_almanac(input).unwrap_err());
+
+        let error = Error::MissingSeeds(Location::new(1, 1, "seed-to-soil map:"));
+        assert_eq!(
+            error,
+            parse_almanac("seed-to-soil map:\n50 98 2").unwrap_err()
+        );
+    }
+}
@@ -0,0 +1,19 @@
+use day5::{parse_almanac, walk};
+
+#[test]
+fn test_walk_a_seed_through_every_map() {
+    let input = "seeds: 79 14
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37";
+    let almanac = parse_almanac(input).unwrap();
+
+    assert_eq!("soil", almanac.maps["seed"].to);
+    assert_eq!(81, walk(79, &almanac.maps, "seed").unwrap());
+    assert_eq!(14, walk(14, &almanac.maps, "seed").unwrap());
+    assert_eq!(79, walk(79, &almanac.maps, "fertilizer").unwrap());
+}
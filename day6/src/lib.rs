@@ -0,0 +1,233 @@
+use std::{error, fmt};
+
+use common::{split_with_offsets, Answer, Location, Solution};
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Error {
+    MissingLine(Location),
+    MissingColon(Location),
+    InvalidNumber(Location),
+    UnevenRecords { times: usize, distances: usize },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::MissingLine(location) => {
+                write!(f, "{}: expected a `Time:` and a `Distance:` line", location)
+            }
+            Error::MissingColon(location) => {
+                write!(
+                    f,
+                    "{}: expected `<name>: <numbers>`, got `{}`",
+                    location, location.text
+                )
+            }
+            Error::InvalidNumber(location) => {
+                write!(f, "{}: invalid number `{}`", location, location.text)
+            }
+            Error::UnevenRecords { times, distances } => write!(
+                f,
+                "every race needs a time and a distance, got {} times and {} distances",
+                times, distances
+            ),
+        }
+    }
+}
+
+impl error::Error for Error {}
+
+fn parse_number(n: &str, location: (usize, usize)) -> Result<u64, Error> {
+    let (line_number, column) = location;
+    n.parse::<u64>()
+        .map_err(|_| Error::InvalidNumber(Location::new(line_number, column, n)))
+}
+
+fn parse_numbers(input: &str, location: (usize, usize)) -> Result<Vec<u64>, Error> {
+    let (line_number, column) = location;
+    split_with_offsets(input, ' ')
+        .filter(|&(_, el)| !el.is_empty())
+        .map(|(offset, el)| parse_number(el, (line_number, column + offset)))
+        .collect::<Result<Vec<u64>, Error>>()
+}
+
+fn get_records(input: &str) -> Result<[(usize, &str); 2], Error> {
+    let content = input.split('\n').collect::<Vec<&str>>();
+    if content.len() < 2 {
+        let location = Location::new(content.len() + 1, 1, "");
+        return Err(Error::MissingLine(location));
+    }
+
+    let mut records = [(0, ""); 2];
+    for (index, line) in content[..2].iter().enumerate() {
+        let colon_index = line
+            .find(':')
+            .ok_or_else(|| Error::MissingColon(Location::new(index + 1, 1, line)))?;
+        // the column where the numbers start, right after the colon
+        records[index] = (colon_index + 2, &line[colon_index + 1..]);
+    }
+
+    Ok(records)
+}
+
+/// Number of ways to hold the button that beat the `distance` record in a
+/// race that lasts `time` milliseconds.
+pub fn bs(time: u64, distance: u64) -> u64 {
+    let (mut lo, mut hi): (u64, u64) = (0, time);
+
+    loop {
+        // not even the best hold beats the record
+        if lo > hi {
+            return 0;
+        }
+
+        let lo_milimeters_per_ms = lo * (time - lo);
+        let hi_milimeters_per_ms = hi * (time - hi);
+
+        if lo_milimeters_per_ms > distance && hi_milimeters_per_ms > distance {
+            break;
+        }
+
+        if lo_milimeters_per_ms <= distance {
+            lo += 1;
+        } else if hi_milimeters_per_ms <= distance {
+            hi -= 1;
+        }
+    }
+
+    (lo..=hi).count() as u64
+}
+
+fn parse_complete_number(input: &str, location: (usize, usize)) -> Result<u64, Error> {
+    let (line_number, column) = location;
+    let pieces = split_with_offsets(input, ' ')
+        .filter(|&(_, el)| !el.is_empty())
+        .collect::<Vec<(usize, &str)>>();
+    let first_column = column + pieces.first().map_or(0, |&(offset, _)| offset);
+
+    parse_number(
+        &pieces
+            .iter()
+            .map(|&(_, el)| el)
+            .collect::<Vec<&str>>()
+            .join(""),
+        (line_number, first_column),
+    )
+}
+
+/// The records read both ways: one race per column for part 1, a single
+/// race with the spaces between digits ignored for part 2.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Records {
+    /// `(time, distance)` of every race.
+    pub races: Vec<(u64, u64)>,
+    pub single_race: (u64, u64),
+}
+
+pub fn parse_records(input: &str) -> Result<Records, Error> {
+    let [(times_column, times), (distances_column, distances)] = get_records(input)?;
+    let times_of_races = parse_numbers(times, (1, times_column))?;
+    let distances_of_races = parse_numbers(distances, (2, distances_column))?;
+    if times_of_races.len() != distances_of_races.len() {
+        return Err(Error::UnevenRecords {
+            times: times_of_races.len(),
+            distances: distances_of_races.len(),
+        });
+    }
+    let races = times_of_races
+        .into_iter()
+        .zip(distances_of_races)
+        .collect::<Vec<(u64, u64)>>();
+    let single_race = (
+        parse_complete_number(times, (1, times_column))?,
+        parse_complete_number(distances, (2, distances_column))?,
+    );
+
+    Ok(Records { races, single_race })
+}
+
+pub fn find_number_of_ways_to_beat_record_part1(records: &Records) -> u64 {
+    records
+        .races
+        .iter()
+        .map(|&(time, distance)| bs(time, distance))
+        .product()
+}
+
+pub fn find_number_of_ways_to_beat_record_part2(records: &Records) -> u64 {
+    let (time, distance) = records.single_race;
+    bs(time, distance)
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+
+    type Input<'a> = Records;
+
+    type Error = Error;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Self::Error> {
+        parse_records(input)
+    }
+
+    fn part1(records: &Self::Input<'_>) -> Result<Answer, Self::Error> {
+        Ok(find_number_of_ways_to_beat_record_part1(records).into())
+    }
+
+    fn part2(records: &Self::Input<'_>) -> Result<Answer, Self::Error> {
+        Ok(find_number_of_ways_to_beat_record_part2(records).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_number_of_ways_to_beat_record_part1() {
+        let input = "Time:      7  15   30
+Distance:  9  40  200";
+
+        assert_eq!(
+            288,
+            find_number_of_ways_to_beat_record_part1(&parse_records(input).unwrap())
+        );
+    }
+
+    #[test]
+    fn test_find_number_of_ways_to_beat_record_part2() {
+        let input = "Time:      7  15   30
+Distance:  9  40  200";
+
+        assert_eq!(
+            71503,
+            find_number_of_ways_to_beat_record_part2(&parse_records(input).unwrap())
+        );
+    }
+
+    #[test]
+    fn test_find_number_of_ways_to_beat_record_reports_malformed_input() {
+        let input = "Time:      7  15   3O
+Distance:  9  40  200";
+        let error = Error::InvalidNumber(Location::new(1, 20, "3O"));
+        assert_eq!(Err(error), parse_records(input));
+
+        let error = Error::MissingLine(Location::new(2, 1, ""));
+        assert_eq!(Err(error), parse_records("Time: 7"));
+
+        let error = Error::UnevenRecords {
+            times: 2,
+            distances: 1,
+        };
+        assert_eq!(Err(error), parse_records("Time: 3 4\nDistance: 1"));
+    }
+
+    #[test]
+    fn test_races_nobody_can_win() {
+        assert_eq!(0, bs(3, 100));
+        assert_eq!(0, bs(0, 0));
+        assert_eq!(2, bs(3, 1));
+    }
+}
@@ -0,0 +1,8 @@
+use day6::bs;
+
+#[test]
+fn test_ways_to_beat_each_record() {
+    assert_eq!(4, bs(7, 9));
+    assert_eq!(8, bs(15, 40));
+    assert_eq!(9, bs(30, 200));
+}
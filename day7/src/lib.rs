@@ -0,0 +1,306 @@
+use std::{cmp::Ordering, collections::HashMap, error, fmt};
+
+use common::{split_with_offsets, Answer, Location, Solution};
+
+const CARD_LABELS: &str = "AKQJT98765432";
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Error {
+    InvalidHand(Location),
+    MissingBid(Location),
+    InvalidBid(Location),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::InvalidHand(location) => write!(
+                f,
+                "{}: expected five cards out of `{}`, got `{}`",
+                location, CARD_LABELS, location.text
+            ),
+            Error::MissingBid(location) => {
+                write!(
+                    f,
+                    "{}: expected `<cards> <bid>`, got `{}`",
+                    location, location.text
+                )
+            }
+            Error::InvalidBid(location) => {
+                write!(f, "{}: invalid bid `{}`", location, location.text)
+            }
+        }
+    }
+}
+
+impl error::Error for Error {}
+
+fn parse_number_u32(n: &str, location: (usize, usize)) -> Result<u32, Error> {
+    let (line_number, column) = location;
+    n.parse::<u32>()
+        .map_err(|_| Error::InvalidBid(Location::new(line_number, column, n)))
+}
+
+#[derive(Debug)]
+pub struct Hand<'a> {
+    pub cards: &'a str,
+    pub bid: u32,
+}
+
+impl<'a> Hand<'a> {
+    pub fn compute_hand_weight(&self) -> i8 {
+        let frecuencies = self.get_frecuencies(self.get_letters_frecuency());
+        self.map_hand_weight(frecuencies)
+    }
+
+    pub fn compute_hand_weight_with_joker(&self) -> i8 {
+        let mut letters_frecuency = self.get_letters_frecuency();
+        let extra_val = match letters_frecuency.remove_entry(&'J') {
+            Some((_, value)) => value,
+            _ => 0,
+        };
+
+        let mut frecuencies = self.get_frecuencies(letters_frecuency);
+        if !frecuencies.is_empty() {
+            frecuencies[0] += extra_val;
+        } else {
+            frecuencies.push(extra_val);
+        }
+
+        self.map_hand_weight(frecuencies)
+    }
+
+    fn get_frecuencies(&self, repeated_letters_map: HashMap<char, i8>) -> Vec<i8> {
+        let mut repeated_letters = repeated_letters_map.values().copied().collect::<Vec<i8>>();
+        repeated_letters.sort_by(|val_a, val_b| val_b.cmp(val_a));
+
+        repeated_letters
+    }
+
+    fn get_letters_frecuency(&self) -> HashMap<char, i8> {
+        let mut repeated_letters: HashMap<char, i8> = HashMap::new();
+        for character in self.cards.chars() {
+            (*repeated_letters.entry(character).or_insert(0)) += 1;
+        }
+
+        repeated_letters
+    }
+
+    fn map_hand_weight(&self, repeated_letters: Vec<i8>) -> i8 {
+        match repeated_letters.len() {
+            1 => 7,
+            2 => repeated_letters[0] + 2,
+            3 => repeated_letters[0] + 1,
+            4 => 2,
+            _ => 1,
+        }
+    }
+}
+
+pub fn map_letter_weight(c: char) -> i8 {
+    match c {
+        'A' => 13,
+        'K' => 12,
+        'Q' => 11,
+        'J' => 10,
+        'T' => 9,
+        '9' => 8,
+        '8' => 7,
+        '7' => 6,
+        '6' => 5,
+        '5' => 4,
+        '4' => 3,
+        '3' => 2,
+        _ => 1,
+    }
+}
+
+pub fn map_letter_weight_joker(c: char) -> i8 {
+    match c {
+        'A' => 13,
+        'K' => 12,
+        'Q' => 11,
+        'T' => 10,
+        '9' => 9,
+        '8' => 8,
+        '7' => 7,
+        '6' => 6,
+        '5' => 5,
+        '4' => 4,
+        '3' => 3,
+        '2' => 2,
+        'J' => 1,
+        _ => 1,
+    }
+}
+
+pub fn parse_hands(input: &str) -> impl Iterator<Item = Result<Hand<'_>, Error>> {
+    input.lines().enumerate().map(|(index, line)| {
+        let line_number = index + 1;
+        let content = split_with_offsets(line, ' ')
+            .filter(|&(_, el)| !el.is_empty())
+            .collect::<Vec<(usize, &str)>>();
+
+        let (cards_offset, cards) = content.first().copied().unwrap_or((0, line));
+        if cards.len() != 5 || !cards.chars().all(|card| CARD_LABELS.contains(card)) {
+            let location = Location::new(line_number, cards_offset + 1, cards);
+            return Err(Error::InvalidHand(location));
+        }
+
+        let (bid_offset, bid) = content
+            .get(1)
+            .copied()
+            .ok_or_else(|| Error::MissingBid(Location::new(line_number, 1, line)))?;
+
+        Ok(Hand {
+            cards,
+            bid: parse_number_u32(bid, (line_number, bid_offset + 1))?,
+        })
+    })
+}
+
+pub fn get_total_winning_hands(hands: &[Hand<'_>]) -> u32 {
+    let mut hands = hands.iter().collect::<Vec<&Hand<'_>>>();
+    hands.sort_by(|hand_a, hand_b| {
+        let hand_a_weight = hand_a.compute_hand_weight();
+        let hand_b_weight = hand_b.compute_hand_weight();
+        if hand_a_weight == hand_b_weight {
+            let hand_a_iter = hand_a.cards.chars();
+            let hand_b_iter = hand_b.cards.chars();
+
+            let mut both_hands_iter = hand_a_iter.zip(hand_b_iter);
+            loop {
+                // identical hands, the stable sort keeps them in input order
+                let Some((card_a, card_b)) = both_hands_iter.next() else {
+                    return Ordering::Equal;
+                };
+
+                let card_a_weight = map_letter_weight(card_a);
+                let card_b_weight = map_letter_weight(card_b);
+
+                if card_a_weight == card_b_weight {
+                    continue;
+                }
+
+                return card_a_weight.cmp(&card_b_weight);
+            }
+        } else {
+            hand_a_weight.cmp(&hand_b_weight)
+        }
+    });
+
+    hands
+        .iter()
+        .enumerate()
+        .map(|(i, hand)| hand.bid * (i as u32 + 1))
+        .sum()
+}
+
+pub fn get_total_winning_hands_part2(hands: &[Hand<'_>]) -> u32 {
+    let mut hands = hands.iter().collect::<Vec<&Hand<'_>>>();
+    hands.sort_by(|hand_a, hand_b| {
+        let hand_a_weight = hand_a.compute_hand_weight_with_joker();
+        let hand_b_weight = hand_b.compute_hand_weight_with_joker();
+        if hand_a_weight == hand_b_weight {
+            let hand_a_iter = hand_a.cards.chars();
+            let hand_b_iter = hand_b.cards.chars();
+
+            let mut both_hands_iter = hand_a_iter.zip(hand_b_iter);
+            loop {
+                // identical hands, the stable sort keeps them in input order
+                let Some((card_a, card_b)) = both_hands_iter.next() else {
+                    return Ordering::Equal;
+                };
+
+                let card_a_weight = map_letter_weight_joker(card_a);
+                let card_b_weight = map_letter_weight_joker(card_b);
+
+                if card_a_weight == card_b_weight {
+                    continue;
+                }
+
+                return card_a_weight.cmp(&card_b_weight);
+            }
+        } else {
+            hand_a_weight.cmp(&hand_b_weight)
+        }
+    });
+
+    hands
+        .iter()
+        .enumerate()
+        .map(|(i, hand)| hand.bid * (i as u32 + 1))
+        .sum()
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+
+    type Input<'a> = Vec<Hand<'a>>;
+
+    type Error = Error;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Self::Error> {
+        parse_hands(input).collect::<Result<Vec<Hand<'_>>, Error>>()
+    }
+
+    fn part1(hands: &Self::Input<'_>) -> Result<Answer, Self::Error> {
+        Ok(get_total_winning_hands(hands).into())
+    }
+
+    fn part2(hands: &Self::Input<'_>) -> Result<Answer, Self::Error> {
+        Ok(get_total_winning_hands_part2(hands).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_total_winnings() {
+        let input = "32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483";
+
+        assert_eq!(6440, get_total_winning_hands(&Day7::parse(input).unwrap()))
+    }
+
+    #[test]
+    fn test_get_total_winnings_part2() {
+        let input = "32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483";
+
+        assert_eq!(
+            5905,
+            get_total_winning_hands_part2(&Day7::parse(input).unwrap())
+        )
+    }
+
+    #[test]
+    fn test_parse_hands_reports_malformed_hands() {
+        let input = "32T3K 765\nT55J5 6B4\nKK677";
+
+        let hands = parse_hands(input).collect::<Vec<_>>();
+
+        assert!(hands[0].is_ok());
+        let error = Error::InvalidBid(Location::new(2, 7, "6B4"));
+        assert_eq!(&error, hands[1].as_ref().unwrap_err());
+        let error = Error::MissingBid(Location::new(3, 1, "KK677"));
+        assert_eq!(&error, hands[2].as_ref().unwrap_err());
+
+        let error = Error::InvalidHand(Location::new(1, 1, "KX677"));
+        assert_eq!(error, Day7::parse("KX677 28").unwrap_err());
+
+        let hands = Day7::parse("32T3K 1\n32T3K 2").unwrap();
+        assert_eq!(1 + 2 * 2, get_total_winning_hands(&hands));
+        assert_eq!(1 + 2 * 2, get_total_winning_hands_part2(&hands));
+    }
+}
@@ -0,0 +1,19 @@
+use day7::{map_letter_weight, map_letter_weight_joker, parse_hands};
+
+#[test]
+fn test_jokers_make_the_strongest_hand_but_are_the_weakest_card() {
+    let hands = parse_hands("KTJJT 220\nQQQJA 483")
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+
+    // two pair and three of a kind
+    assert!(hands[0].compute_hand_weight() < hands[1].compute_hand_weight());
+    // four of a kind both
+    assert_eq!(
+        hands[0].compute_hand_weight_with_joker(),
+        hands[1].compute_hand_weight_with_joker()
+    );
+
+    assert!(map_letter_weight('J') > map_letter_weight('T'));
+    assert!(map_letter_weight_joker('J') < map_letter_weight_joker('2'));
+}
@@ -0,0 +1,340 @@
+use std::{collections::HashMap, error, fmt};
+
+use common::{split_with_offsets, Answer, Location, Solution};
+
+#[derive(Debug, Clone, Copy)]
+pub enum Directions {
+    Left = 0,
+    Right = 1,
+}
+
+impl Directions {
+    pub fn new(character: char) -> Option<Self> {
+        match character {
+            'L' => Some(Directions::Left),
+            'R' => Some(Directions::Right),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Error {
+    InvalidInstructions(Location),
+    MissingNetwork(Location),
+    InvalidNode(Location),
+    UnknownNode(Location),
+    MissingStart(String),
+    MissingEnd(String),
+    NeverEnds(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::InvalidInstructions(location) => write!(
+                f,
+                "{}: expected a sequence of `L` and `R`, got `{}`",
+                location, location.text
+            ),
+            Error::MissingNetwork(location) => {
+                write!(f, "{}: expected the network after a blank line", location)
+            }
+            Error::InvalidNode(location) => write!(
+                f,
+                "{}: expected `<node> = (<left>, <right>)`, got `{}`",
+                location, location.text
+            ),
+            Error::UnknownNode(location) => {
+                write!(f, "{}: node `{}` is never defined", location, location.text)
+            }
+            Error::MissingStart(node) => write!(f, "the network has no `{}` node", node),
+            Error::MissingEnd(node) => {
+                write!(f, "the network has no `{}` node to end on", node)
+            }
+            Error::NeverEnds(node) => {
+                write!(f, "the path from `{}` never reaches an end node", node)
+            }
+        }
+    }
+}
+
+impl error::Error for Error {}
+
+fn parse_instructions(input: &str) -> Result<Vec<Directions>, Error> {
+    let instructions = input
+        .chars()
+        .rev()
+        .map(Directions::new)
+        .collect::<Option<Vec<_>>>();
+
+    match instructions {
+        Some(instructions) if !instructions.is_empty() => Ok(instructions),
+        _ => Err(Error::InvalidInstructions(Location::new(1, 1, input))),
+    }
+}
+
+fn parse_graph(input: &str, first_line_number: usize) -> Result<HashMap<&str, Vec<&str>>, Error> {
+    // every node with the location where it is referenced, checked once the graph is complete
+    let mut references: Vec<(&str, Location)> = vec![];
+    let mut graph: HashMap<&str, Vec<&str>> = HashMap::new();
+    for (index, line) in input.lines().enumerate() {
+        let line_number = first_line_number + index;
+        let invalid_node = || Error::InvalidNode(Location::new(line_number, 1, line));
+
+        let (key, value) = line.split_once('=').ok_or_else(invalid_node)?;
+        let value_offset = key.len() + 1;
+        let key = key.trim_end();
+
+        let mut nodes = vec![];
+        for (offset, el) in split_with_offsets(value, ',') {
+            let node = el.trim().trim_start_matches('(').trim_end_matches(')');
+            let column = value_offset + offset + el.find(node).unwrap_or(0) + 1;
+            references.push((node, Location::new(line_number, column, node)));
+            nodes.push(node);
+        }
+
+        if key.is_empty() || nodes.len() != 2 || nodes.iter().any(|node| node.is_empty()) {
+            return Err(invalid_node());
+        }
+
+        graph.insert(key, nodes);
+    }
+
+    if let Some((_, location)) = references
+        .into_iter()
+        .find(|(node, _)| !graph.contains_key(node))
+    {
+        return Err(Error::UnknownNode(location));
+    }
+
+    Ok(graph)
+}
+
+#[derive(Debug)]
+pub struct Network<'a> {
+    /// Reversed, so the next instruction can be popped from the end.
+    pub instructions: Vec<Directions>,
+    /// The left and right node of every node.
+    pub graph: HashMap<&'a str, Vec<&'a str>>,
+}
+
+pub fn parse_network(input: &str) -> Result<Network<'_>, Error> {
+    let (instructions, graph) = input.split_once("\n\n").ok_or_else(|| {
+        let line_number = input.lines().count() + 1;
+        Error::MissingNetwork(Location::new(line_number, 1, ""))
+    })?;
+    let graph_line_number = instructions.lines().count() + 2;
+
+    Ok(Network {
+        instructions: parse_instructions(instructions)?,
+        graph: parse_graph(graph, graph_line_number)?,
+    })
+}
+
+/// Past this many steps a walk is back at a node it was on at the same
+/// point of the instructions, and goes round in circles.
+fn longest_path(network: &Network<'_>) -> i64 {
+    (network.graph.len() * network.instructions.len()) as i64
+}
+
+pub fn calculate_steps_part1(network: &Network<'_>) -> Result<i64, Error> {
+    let Network {
+        instructions,
+        graph,
+    } = network;
+
+    let mut steps = 0;
+    let mut stack: Vec<Directions> = instructions.clone();
+    let mut adjacent_nodes = graph
+        .get("AAA")
+        .ok_or_else(|| Error::MissingStart("AAA".to_string()))?;
+    if !graph.contains_key("ZZZ") {
+        return Err(Error::MissingEnd("ZZZ".to_string()));
+    }
+
+    'outer_loop: loop {
+        steps += 1;
+        if steps > longest_path(network) {
+            return Err(Error::NeverEnds("AAA".to_string()));
+        }
+        let instruction = stack.pop().unwrap();
+        let node = adjacent_nodes[instruction as usize];
+
+        match node {
+            "ZZZ" => break 'outer_loop,
+            _ => {
+                adjacent_nodes = graph.get(node).unwrap();
+
+                if stack.is_empty() {
+                    stack = instructions.clone();
+                }
+            }
+        }
+    }
+
+    Ok(steps)
+}
+
+pub fn calculate_steps_part2(network: &Network<'_>) -> Result<i64, Error> {
+    let Network {
+        instructions,
+        graph,
+    } = network;
+
+    let all_nodes_that_ends_in_a = graph
+        .keys()
+        .filter(|&&node_key| node_key.ends_with('A'))
+        .collect::<Vec<_>>();
+    if all_nodes_that_ends_in_a.is_empty() {
+        return Err(Error::MissingStart("..A".to_string()));
+    }
+    if !graph.keys().any(|node| node.ends_with('Z')) {
+        return Err(Error::MissingEnd("..Z".to_string()));
+    }
+
+    let mut stack: Vec<Directions> = instructions.clone();
+    let mut steps_per_node: Vec<usize> = vec![];
+
+    for &node in all_nodes_that_ends_in_a {
+        let mut steps: usize = 0;
+        let mut adjacent_nodes = graph.get(node).unwrap();
+
+        'outer_loop: loop {
+            steps += 1;
+            if steps as i64 > longest_path(network) {
+                return Err(Error::NeverEnds(node.to_string()));
+            }
+            let instruction = stack.pop().unwrap();
+            let node = adjacent_nodes[instruction as usize];
+
+            if node.ends_with('Z') {
+                break 'outer_loop;
+            } else {
+                adjacent_nodes = graph.get(node).unwrap();
+
+                if stack.is_empty() {
+                    stack = instructions.clone();
+                }
+            }
+        }
+
+        steps_per_node.push(steps);
+        stack = instructions.clone();
+    }
+
+    Ok(lcm(steps_per_node.as_slice()) as i64)
+}
+
+pub fn lcm(nums: &[usize]) -> usize {
+    if nums.len() == 1 {
+        return nums[0];
+    }
+    let a = nums[0];
+    let b = lcm(&nums[1..]);
+    a * b / gcd_of_two_numbers(a, b)
+}
+
+fn gcd_of_two_numbers(a: usize, b: usize) -> usize {
+    if b == 0 {
+        return a;
+    }
+    gcd_of_two_numbers(b, a % b)
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u8 = 8;
+
+    type Input<'a> = Network<'a>;
+
+    type Error = Error;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Self::Error> {
+        parse_network(input)
+    }
+
+    fn part1(network: &Self::Input<'_>) -> Result<Answer, Self::Error> {
+        Ok(calculate_steps_part1(network)?.into())
+    }
+
+    fn part2(network: &Self::Input<'_>) -> Result<Answer, Self::Error> {
+        Ok(calculate_steps_part2(network)?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calculate_steps_part1() {
+        let input = "RL
+
+AAA = (BBB, CCC)
+BBB = (DDD, EEE)
+CCC = (ZZZ, GGG)
+DDD = (DDD, DDD)
+EEE = (EEE, EEE)
+GGG = (GGG, GGG)
+ZZZ = (ZZZ, ZZZ)";
+
+        assert_eq!(
+            2,
+            calculate_steps_part1(&parse_network(input).unwrap()).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_calculate_steps_part2() {
+        let input = "LR
+
+11A = (11B, XXX)
+11B = (XXX, 11Z)
+11Z = (11B, XXX)
+22A = (22B, XXX)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22B, 22B)
+XXX = (XXX, XXX)";
+
+        assert_eq!(
+            6,
+            calculate_steps_part2(&parse_network(input).unwrap()).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_parse_network_reports_malformed_input() {
+        let error = Error::InvalidInstructions(Location::new(1, 1, "RLX"));
+        assert_eq!(error, parse_network("RLX\n\nAAA = (AAA, AAA)").unwrap_err());
+
+        let error = Error::InvalidNode(Location::new(4, 1, "BBB = (AAA)"));
+        let input = "RL\n\nAAA = (BBB, BBB)\nBBB = (AAA)";
+        assert_eq!(error, parse_network(input).unwrap_err());
+
+        let error = Error::UnknownNode(Location::new(3, 13, "CCC"));
+        let input = "RL\n\nAAA = (BBB, CCC)\nBBB = (AAA, AAA)";
+        assert_eq!(error, parse_network(input).unwrap_err());
+
+        let network = parse_network("RL\n\nBBB = (BBB, BBB)").unwrap();
+        let error = Error::MissingStart("AAA".to_string());
+        assert_eq!(error, calculate_steps_part1(&network).unwrap_err());
+    }
+
+    #[test]
+    fn test_networks_that_never_end() {
+        let network = parse_network("RL\n\nAAA = (BBB, BBB)\nBBB = (AAA, AAA)").unwrap();
+        let error = Error::MissingEnd("ZZZ".to_string());
+        assert_eq!(error, calculate_steps_part1(&network).unwrap_err());
+        let error = Error::MissingEnd("..Z".to_string());
+        assert_eq!(error, calculate_steps_part2(&network).unwrap_err());
+
+        let input = "RL\n\nAAA = (BBB, BBB)\nBBB = (AAA, AAA)\nZZZ = (AAA, AAA)";
+        let network = parse_network(input).unwrap();
+        let error = Error::NeverEnds("AAA".to_string());
+        assert_eq!(error, calculate_steps_part1(&network).unwrap_err());
+        assert_eq!(error, calculate_steps_part2(&network).unwrap_err());
+    }
+}
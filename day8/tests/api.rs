@@ -0,0 +1,17 @@
+use day8::{lcm, parse_network, Directions};
+
+#[test]
+fn test_parse_network_and_lcm() {
+    let network =
+        parse_network("RL\n\nAAA = (BBB, CCC)\nBBB = (AAA, AAA)\nCCC = (AAA, BBB)").unwrap();
+
+    assert!(matches!(
+        network.instructions[..],
+        [Directions::Left, Directions::Right]
+    ));
+    assert_eq!(vec!["BBB", "CCC"], network.graph["AAA"]);
+    assert_eq!(3, network.graph.len());
+
+    assert_eq!(6, lcm(&[2, 3]));
+    assert_eq!(12, lcm(&[4, 6, 12]));
+}
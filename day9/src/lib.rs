@@ -0,0 +1,187 @@
+use std::{error, fmt};
+
+use common::{split_with_offsets, Answer, Location, Solution};
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Error {
+    InvalidNumber(Location),
+    NoZeroDifferences(usize),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::InvalidNumber(location) => {
+                write!(f, "{}: invalid number `{}`", location, location.text)
+            }
+            Error::NoZeroDifferences(line_number) => write!(
+                f,
+                "line {}: the differences run out before they are all zeros",
+                line_number
+            ),
+        }
+    }
+}
+
+impl error::Error for Error {}
+
+fn parse_numbers(input: &str, line_number: usize) -> impl Iterator<Item = Result<i64, Error>> + '_ {
+    split_with_offsets(input, ' ').map(move |(offset, n)| {
+        n.parse::<i64>()
+            .map_err(|_| Error::InvalidNumber(Location::new(line_number, offset + 1, n)))
+    })
+}
+
+pub fn parse_histories(input: &str) -> Result<Vec<Vec<i64>>, Error> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| parse_numbers(line, index + 1).collect::<Result<Vec<i64>, _>>())
+        .collect::<Result<Vec<Vec<i64>>, Error>>()
+}
+
+/// The history followed by its sequences of differences, down to the one
+/// that is all zeros, or `None` when they run out of numbers before.
+pub fn build_sequences(history: &[i64]) -> Option<Vec<Vec<i64>>> {
+    let mut sequence = history.to_vec();
+    let mut sequences = Vec::new();
+    sequences.push(sequence.clone());
+
+    loop {
+        sequence = sequence
+            .windows(2)
+            .map(|chunk| chunk[1] - chunk[0])
+            .collect::<Vec<i64>>();
+        if sequence.is_empty() {
+            return None;
+        }
+
+        sequences.push(sequence.clone());
+        if sequence.iter().all(|&el| el == 0) {
+            break;
+        }
+    }
+
+    Some(sequences)
+}
+
+fn sequences_of(index: usize, history: &[i64]) -> Result<Vec<Vec<i64>>, Error> {
+    build_sequences(history).ok_or(Error::NoZeroDifferences(index + 1))
+}
+
+pub fn get_total_extrapolated_values(histories: &[Vec<i64>]) -> Result<i64, Error> {
+    histories
+        .iter()
+        .enumerate()
+        .map(|(index, history)| {
+            Ok(sequences_of(index, history)?
+                .iter()
+                .map(|sequence| sequence[sequence.len() - 1])
+                .sum::<i64>())
+        })
+        .sum::<Result<i64, Error>>()
+}
+
+pub fn get_total_extrapolated_values_backwards(histories: &[Vec<i64>]) -> Result<i64, Error> {
+    histories
+        .iter()
+        .enumerate()
+        .map(|(index, history)| {
+            let mut result: i64 = 0;
+            for el in sequences_of(index, history)?
+                .iter()
+                .rev()
+                .map(|sequence| sequence[0])
+            {
+                result = el - result;
+            }
+
+            Ok(result)
+        })
+        .sum::<Result<i64, Error>>()
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: u8 = 9;
+
+    type Input<'a> = Vec<Vec<i64>>;
+
+    type Error = Error;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Self::Error> {
+        parse_histories(input)
+    }
+
+    fn part1(histories: &Self::Input<'_>) -> Result<Answer, Self::Error> {
+        Ok(get_total_extrapolated_values(histories)?.into())
+    }
+
+    fn part2(histories: &Self::Input<'_>) -> Result<Answer, Self::Error> {
+        Ok(get_total_extrapolated_values_backwards(histories)?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_total_extrapolated_values() {
+        let input = "0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45";
+
+        assert_eq!(
+            Ok(114),
+            get_total_extrapolated_values(&parse_histories(input).unwrap())
+        );
+    }
+
+    #[test]
+    fn test_get_total_extrapolated_values_backwards() {
+        let input = "0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45";
+
+        assert_eq!(
+            Ok(2),
+            get_total_extrapolated_values_backwards(&parse_histories(input).unwrap())
+        );
+    }
+
+    #[test]
+    fn test_histories_that_never_reach_zeros() {
+        let histories = parse_histories(
+            "0 3 6
+1 2 4
+5",
+        )
+        .unwrap();
+
+        assert_eq!(None, build_sequences(&histories[1]));
+        assert_eq!(None, build_sequences(&histories[2]));
+        let error = Error::NoZeroDifferences(2);
+        assert_eq!(Err(error), get_total_extrapolated_values(&histories));
+        let histories = parse_histories("0 3 6\n5").unwrap();
+        let error = Error::NoZeroDifferences(2);
+        assert_eq!(
+            Err(error),
+            get_total_extrapolated_values_backwards(&histories)
+        );
+    }
+
+    #[test]
+    fn test_parse_histories_reports_invalid_numbers() {
+        let error = Error::InvalidNumber(Location::new(2, 5, "-"));
+        assert_eq!(Err(error), parse_histories("0 3 6\n1 3 - 10"));
+
+        let error = Error::InvalidNumber(Location::new(2, 1, ""));
+        assert_eq!(Err(error), parse_histories("0 3 6\n\n1 3 6"));
+
+        assert_eq!(Ok(vec![vec![-5, 5]]), parse_histories("-5 +5"));
+        let error = Error::InvalidNumber(Location::new(1, 1, "--5"));
+        assert_eq!(Err(error), parse_histories("--5 -+5"));
+    }
+}
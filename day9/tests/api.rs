@@ -0,0 +1,15 @@
+use day9::build_sequences;
+
+#[test]
+fn test_build_sequences_down_to_zeros() {
+    let sequences = build_sequences(&[0, 3, 6, 9, 12, 15]).unwrap();
+
+    assert_eq!(
+        vec![
+            vec![0, 3, 6, 9, 12, 15],
+            vec![3, 3, 3, 3, 3],
+            vec![0, 0, 0, 0]
+        ],
+        sequences
+    );
+}